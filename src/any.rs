@@ -0,0 +1,109 @@
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// [`RwApi`](crate::RwApi) implementor holding one of two backends, selected at runtime.
+///
+/// Unlike [`DynRwApi`](crate::DynRwApi), guards aren't boxed:
+/// they are [`AnyGuard`]s over the guards of both backends,
+/// which upgrades and downgrades dispatch to the backend the guard came from.
+/// Backends sharing a `Target` can be combined freely,
+/// and a nested [`AnyRwApi`] selects among more than two of them.
+/// A shared reference to it can only write if both backends implement
+/// [`SharedWriteApi`](crate::SharedWriteApi).
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     AnyRwApi,
+///     DowngradableWriteGuard,
+///     RwApi,
+///     RwApiWrapperOwned,
+///     UpgradableReadApi,
+///     UpgradableReadGuard,
+///     WriteApi,
+/// };
+///
+/// type Counter<'a> = AnyRwApi<RwApiWrapperOwned<u64>, &'a RwLock<u64>>;
+///
+/// fn counter(shared: Option<&RwLock<u64>>) -> Counter<'_> {
+///     match shared {
+///         Some(lock) => AnyRwApi::Shared(lock),
+///         None => AnyRwApi::Local(RwApiWrapperOwned(0)),
+///     }
+/// }
+///
+/// fn bump(mut x: impl RwApi<Target=u64>) -> u64 {
+///     let guard = x.upgradable_read();
+///     if *guard < 10 {
+///         let mut guard = guard.upgrade_to_downgradable();
+///         *guard += 1;
+///         *guard.downgrade()
+///     } else {
+///         *guard
+///     }
+/// }
+///
+/// let lock = RwLock::new(10);
+/// assert_eq!(bump(counter(None)), 1);
+/// assert_eq!(bump(counter(Some(&lock))), 10);
+///
+/// let mut local = counter(None);
+/// *local.write() = 5;
+/// assert_eq!(*local.upgradable_read(), 5);
+/// assert_eq!(bump(&mut local), 6);
+///
+/// let shared: AnyRwApi<RwLock<u64>, &RwLock<u64>> = AnyRwApi::Local(RwLock::new(0));
+/// assert_eq!(bump(&shared), 1);
+/// assert_eq!(bump(&shared), 2);
+/// ```
+pub enum AnyRwApi<L, S> {
+    /// Single-threaded backend, such as [`RwApiWrapperOwned`](crate::RwApiWrapperOwned).
+    Local(L),
+    /// Shared backend, such as [`RwLock`](parking_lot::RwLock).
+    Shared(S),
+}
+
+/// Guard returned by [`AnyRwApi`], holding a guard of either of its backends.
+pub enum AnyGuard<L, S> {
+    /// Guard of the [`AnyRwApi::Local`] backend.
+    Local(L),
+    /// Guard of the [`AnyRwApi::Shared`] backend.
+    Shared(S),
+}
+
+impl<L: Deref, S: Deref<Target=L::Target>> Deref for AnyGuard<L, S>
+{
+    type Target = L::Target;
+
+    #[inline(always)]
+    fn deref(&self) -> &L::Target {
+        match self {
+            Self::Local(guard) => guard,
+            Self::Shared(guard) => guard,
+        }
+    }
+}
+
+impl<L: DerefMut, S: DerefMut<Target=L::Target>> DerefMut for AnyGuard<L, S>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut L::Target {
+        match self {
+            Self::Local(guard) => guard,
+            Self::Shared(guard) => guard,
+        }
+    }
+}
+
+impl<L: Deref, S: Deref<Target=L::Target>> fmt::Debug for AnyGuard<L, S>
+    where L::Target: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use {
+    crate::GuardedTarget,
+    std::ops::{Deref, DerefMut},
+};
+
+/// Type-erased read guard returned by [`DynReadApi::read_dyn`].
+pub type DynReadGuard<'a, T> = Box<dyn Deref<Target=T> + 'a>;
+
+/// Type-erased write guard returned by [`DynWriteApi::write_dyn`].
+pub type DynWriteGuard<'a, T> = Box<dyn DerefMut<Target=T> + 'a>;
+
+/// Type-erased upgradable read guard
+/// returned by [`DynUpgradableReadApi::upgradable_read_dyn`].
+pub type DynUpgradableGuard<'a, T> = Box<dyn DynUpgradableReadGuard<'a, Target=T> + 'a>;
+
+/// Type-erased downgradable write guard
+/// returned by [`DynDowngradableWriteApi::downgradable_write_dyn`].
+pub type DynDowngradableGuard<'a, T> = Box<dyn DynDowngradableWriteGuard<'a, Target=T> + 'a>;
+
+/// Object-safe analogue of [`RwApi`](crate::RwApi) returning type-erased guards.
+///
+/// Implemented for every [`RwApi`](crate::RwApi) implementor,
+/// so that different backends sharing a single `Target` can be stored together.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::RefCell;
+/// use parking_lot::RwLock;
+/// use read_write_api::{DynRwApi, RwApiWrapperOwned};
+///
+/// #[derive(Default)]
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// let mut registry: Vec<Box<dyn DynRwApi<Target=Config>>> = vec![
+///     Box::new(RwLock::new(Config::default())),
+///     Box::new(RefCell::new(Config::default())),
+///     Box::new(RwApiWrapperOwned(Config::default())),
+/// ];
+///
+/// for backend in &mut registry {
+///     let guard = backend.upgradable_read_dyn();
+///     if !guard.verbose {
+///         let mut guard = guard.upgrade_to_downgradable_dyn();
+///         guard.verbose = true;
+///         assert!(guard.downgrade_dyn().verbose)
+///     }
+/// }
+///
+/// assert!(registry.iter().all(|backend| backend.read_dyn().verbose));
+/// assert!(registry[0].write_dyn().verbose);
+/// assert!(registry[1].downgradable_write_dyn().downgrade_to_upgradable_dyn().verbose);
+/// ```
+pub trait DynRwApi: DynReadApi
++ DynWriteApi
++ DynUpgradableReadApi
++ DynDowngradableWriteApi
+{}
+
+/// Object-safe analogue of [`ReadApi`](crate::ReadApi).
+///
+/// # Example
+///
+/// See the [`DynRwApi`] docs for usage examples.
+pub trait DynReadApi: GuardedTarget
+{
+    /// Type-erased [`ReadApi::read`](crate::ReadApi::read).
+    fn read_dyn(&self) -> DynReadGuard<'_, Self::Target>;
+}
+
+/// Object-safe analogue of [`WriteApi`](crate::WriteApi).
+///
+/// # Example
+///
+/// See the [`DynRwApi`] docs for usage examples.
+pub trait DynWriteApi: GuardedTarget
+{
+    /// Type-erased [`WriteApi::write`](crate::WriteApi::write).
+    fn write_dyn(&mut self) -> DynWriteGuard<'_, Self::Target>;
+}
+
+/// Object-safe analogue of [`UpgradableReadApi`](crate::UpgradableReadApi).
+///
+/// # Example
+///
+/// See the [`DynRwApi`] docs for usage examples.
+pub trait DynUpgradableReadApi: GuardedTarget
+{
+    /// Type-erased [`UpgradableReadApi::upgradable_read`](crate::UpgradableReadApi::upgradable_read).
+    fn upgradable_read_dyn(&mut self) -> DynUpgradableGuard<'_, Self::Target>;
+}
+
+/// Object-safe analogue of [`DowngradableWriteApi`](crate::DowngradableWriteApi).
+///
+/// # Example
+///
+/// See the [`DynRwApi`] docs for usage examples.
+pub trait DynDowngradableWriteApi: GuardedTarget
+{
+    /// Type-erased
+    /// [`DowngradableWriteApi::downgradable_write`](crate::DowngradableWriteApi::downgradable_write).
+    fn downgradable_write_dyn(&mut self) -> DynDowngradableGuard<'_, Self::Target>;
+}
+
+/// Object-safe analogue of [`UpgradableReadGuard`](crate::UpgradableReadGuard).
+///
+/// # Example
+///
+/// See the [`DynRwApi`] docs for usage examples.
+pub trait DynUpgradableReadGuard<'a>: Deref
+{
+    /// Type-erased [`UpgradableReadGuard::upgrade`](crate::UpgradableReadGuard::upgrade).
+    fn upgrade_dyn(self: Box<Self>) -> DynWriteGuard<'a, Self::Target>;
+
+    /// Type-erased
+    /// [`UpgradableReadGuard::upgrade_to_downgradable`](crate::UpgradableReadGuard::upgrade_to_downgradable).
+    fn upgrade_to_downgradable_dyn(self: Box<Self>) -> DynDowngradableGuard<'a, Self::Target>;
+}
+
+/// Object-safe analogue of [`DowngradableWriteGuard`](crate::DowngradableWriteGuard).
+///
+/// # Example
+///
+/// See the [`DynRwApi`] docs for usage examples.
+pub trait DynDowngradableWriteGuard<'a>: DerefMut
+{
+    /// Type-erased [`DowngradableWriteGuard::downgrade`](crate::DowngradableWriteGuard::downgrade).
+    fn downgrade_dyn(self: Box<Self>) -> DynReadGuard<'a, Self::Target>;
+
+    /// Type-erased
+    /// [`DowngradableWriteGuard::downgrade_to_upgradable`](crate::DowngradableWriteGuard::downgrade_to_upgradable).
+    fn downgrade_to_upgradable_dyn(self: Box<Self>) -> DynUpgradableGuard<'a, Self::Target>;
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// [`RwApi`](crate::RwApi) adapter emulating upgradable reads and downgradable writes
+/// over any [`BasicRwApi`](crate::BasicRwApi) implementor.
+///
+/// Upgradable read guards and downgraded guards hold the write guard of the wrapped object,
+/// so upgrades and downgrades are atomic and never block,
+/// at the cost of excluding other readers for the guard lifetime.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::RwLock;
+/// use read_write_api::{DowngradableWriteGuard, Emulated, RwApi, UpgradableReadGuard};
+///
+/// fn do_something(mut x: impl RwApi<Target=u64>) -> u64 {
+///     let guard = x.upgradable_read();
+///     if *guard == 1 {
+///         let mut guard = guard.upgrade_to_downgradable();
+///         *guard = 2;
+///         *guard.downgrade()
+///     } else {
+///         *guard
+///     }
+/// }
+///
+/// let lock = RwLock::new(1);
+/// assert_eq!(do_something(Emulated(&lock)), 2);
+/// assert_eq!(do_something(Emulated(&lock)), 2);
+/// assert_eq!(do_something(Emulated(RwLock::new(3))), 3);
+///
+/// let mut owned = Emulated(RwLock::new(1));
+/// assert_eq!(do_something(&mut owned), 2);
+/// let shared = Emulated(RwLock::new(1));
+/// assert_eq!(do_something(&shared), 2);
+/// ```
+pub struct Emulated<L: ?Sized>(
+    /// Wrapped [`BasicRwApi`](crate::BasicRwApi) implementor.
+    pub L
+);
+
+/// Read guard emulated over the write guard `G`.
+///
+/// Returned by [`Emulated`] as an upgradable read guard and as a downgraded guard.
+pub struct EmulatedReadGuard<G>(G);
+
+/// Write guard `G` returned by [`Emulated`] as an upgraded or a downgradable guard.
+pub struct EmulatedWriteGuard<G>(G);
+
+impl<G> EmulatedReadGuard<G>
+{
+    #[inline(always)]
+    pub(crate) fn new(guard: G) -> Self {
+        Self(guard)
+    }
+
+    #[inline(always)]
+    pub(crate) fn into_inner(self) -> G {
+        self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn inner_mut(&mut self) -> &mut G {
+        &mut self.0
+    }
+}
+
+impl<G> EmulatedWriteGuard<G>
+{
+    #[inline(always)]
+    pub(crate) fn new(guard: G) -> Self {
+        Self(guard)
+    }
+
+    #[inline(always)]
+    pub(crate) fn into_inner(self) -> G {
+        self.0
+    }
+}
+
+impl<G: Deref> Deref for EmulatedReadGuard<G>
+{
+    type Target = G::Target;
+
+    #[inline(always)]
+    fn deref(&self) -> &G::Target {
+        &self.0
+    }
+}
+
+impl<G: Deref> Deref for EmulatedWriteGuard<G>
+{
+    type Target = G::Target;
+
+    #[inline(always)]
+    fn deref(&self) -> &G::Target {
+        &self.0
+    }
+}
+
+impl<G: DerefMut> DerefMut for EmulatedWriteGuard<G>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut G::Target {
+        &mut self.0
+    }
+}
+
+impl<G: Deref> fmt::Debug for EmulatedReadGuard<G>
+    where G::Target: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<G: Deref> fmt::Debug for EmulatedWriteGuard<G>
+    where G::Target: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use std::{
+    cell::{BorrowError, BorrowMutError},
+    error::Error,
+    fmt,
+    sync::PoisonError,
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+/// Reason why a lock could not be acquired without blocking.
+///
+/// Every backend reports its failures through this single type,
+/// so generic code handles all of them the same way.
+///
+/// # Example
+///
+/// See the [`TryReadApi`](crate::TryReadApi) docs for usage examples.
+pub enum TryLockError {
+    /// The lock is held by someone else in an incompatible mode.
+    WouldBlock,
+    /// Another thread panicked while holding the lock.
+    ///
+    /// Only the fallible methods report poisoning:
+    /// the infallible ones recover the guard, just like the never-poisoning
+    /// [`parking_lot::RwLock`] does.
+    Poisoned,
+    /// The [`RefCell`](std::cell::RefCell) is already borrowed in an incompatible mode.
+    AlreadyBorrowed,
+}
+
+impl fmt::Display for TryLockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(
+            match self {
+                Self::WouldBlock => "lock acquisition would block",
+                Self::Poisoned => "lock is poisoned",
+                Self::AlreadyBorrowed => "value is already borrowed",
+            }
+        )
+    }
+}
+
+impl Error for TryLockError {}
+
+impl<G> From<std::sync::TryLockError<G>> for TryLockError {
+    #[inline]
+    fn from(err: std::sync::TryLockError<G>) -> Self {
+        match err {
+            std::sync::TryLockError::WouldBlock => Self::WouldBlock,
+            std::sync::TryLockError::Poisoned(_) => Self::Poisoned,
+        }
+    }
+}
+
+impl<G> From<PoisonError<G>> for TryLockError {
+    #[inline]
+    fn from(_: PoisonError<G>) -> Self {
+        Self::Poisoned
+    }
+}
+
+impl From<BorrowError> for TryLockError {
+    #[inline]
+    fn from(_: BorrowError) -> Self {
+        Self::AlreadyBorrowed
+    }
+}
+
+impl From<BorrowMutError> for TryLockError {
+    #[inline]
+    fn from(_: BorrowMutError) -> Self {
+        Self::AlreadyBorrowed
+    }
+}
\ No newline at end of file
@@ -0,0 +1,198 @@
+use {
+    crate::{
+        DowngradableWriteGuard,
+        LockIdentityApi,
+        ReadApi,
+        UpgradableReadApi,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::{cmp::Ordering, mem},
+};
+
+/// Provides value-level convenience operations over [`ReadApi`].
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{ReadApiExt, RwApiWrapperOwned};
+///
+/// assert_eq!(RwLock::new(vec![1]).cloned(), [1]);
+/// assert_eq!(RwLock::new(1).copied(), 1);
+/// assert_eq!((&RwApiWrapperOwned(2)).copied(), 2);
+/// ```
+pub trait ReadApiExt: ReadApi
+{
+    /// Returns a clone of the guarded data.
+    fn cloned(&self) -> Self::Target
+        where Self::Target: Clone;
+
+    /// Returns a copy of the guarded data.
+    fn copied(&self) -> Self::Target
+        where Self::Target: Copy;
+}
+
+/// Provides value-level convenience operations over the [`RwApi`](crate::RwApi) family.
+///
+/// Every operation acquires the lock only once, so it's atomic
+/// with respect to other users of the same lock.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{ReadApiExt, RwApiExt, RwApiWrapperOwned};
+///
+/// fn exercise(mut x: impl RwApiExt<Target=u64> + ReadApiExt) {
+///     x.update(|value| value + 1);
+///     assert_eq!(x.copied(), 2);
+///     assert_eq!(x.replace(5), 2);
+///     assert_eq!(x.take(), 5);
+///     assert_eq!(x.compare_and_set(&1, 3), Err(3));
+///     assert_eq!(x.compare_and_set(&0, 3), Ok(0));
+///     assert_eq!(x.fetch_update(|value| (*value < 10).then(|| value * 2)), Some(3));
+///     assert_eq!(x.fetch_update(|value| (*value < 5).then(|| value * 2)), None);
+///     assert_eq!(x.copied(), 6);
+/// }
+///
+/// exercise(RwLock::new(1));
+/// exercise(&RwLock::new(1));
+/// exercise(RwApiWrapperOwned(1));
+/// ```
+pub trait RwApiExt: WriteApi + UpgradableReadApi
+{
+    /// Replaces the guarded data with `f` applied to it.
+    fn update<F>(&mut self, f: F)
+        where
+            Self::Target: Sized,
+            F: FnOnce(&Self::Target) -> Self::Target;
+
+    /// Replaces the guarded data with `value`, returning the previous one.
+    fn replace(&mut self, value: Self::Target) -> Self::Target
+        where Self::Target: Sized;
+
+    /// Replaces the guarded data with its default value, returning the previous one.
+    fn take(&mut self) -> Self::Target
+        where Self::Target: Default;
+
+    /// Replaces the guarded data with `new` if it's equal to `expected`.
+    ///
+    /// Returns the previous data on success and gives `new` back on failure.
+    /// The lock is upgraded only on success.
+    fn compare_and_set(&mut self, expected: &Self::Target, new: Self::Target)
+        -> Result<Self::Target, Self::Target>
+        where Self::Target: PartialEq + Sized;
+
+    /// Replaces the guarded data with the value returned by `f`, if any.
+    ///
+    /// Returns the previous data if it was replaced.
+    /// The lock is upgraded only if `f` returns [`Some`].
+    fn fetch_update<F>(&mut self, f: F) -> Option<Self::Target>
+        where
+            Self::Target: Sized,
+            F: FnOnce(&Self::Target) -> Option<Self::Target>;
+}
+
+/// Read guard obtained by downgrading an upgradable read guard of `L`,
+/// whether it was upgraded in between or not.
+pub type DowngradedGuard<'a, L> = <
+    <<L as UpgradableReadApi>::UpgradableReadGuard<'a> as UpgradableReadGuard>::UpgradeToDowngradableResult
+    as DowngradableWriteGuard
+>::DowngradeResult;
+
+/// Provides double-checked initialization helpers over [`UpgradableReadApi`].
+///
+/// The check is performed under an upgradable read guard,
+/// which excludes other writers and upgradable readers,
+/// so the data is upgraded at most once and never checked twice.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{RwApiWrapperOwned, UpgradableReadApi, UpgradableReadApiExt};
+///
+/// fn load(mut x: impl UpgradableReadApi<Target=Option<String>>) -> usize {
+///     let guard = x.get_or_init_with(Option::is_some, |_| Some("config".into()));
+///     guard.as_ref().unwrap().len()
+/// }
+///
+/// fn non_empty(mut x: impl UpgradableReadApi<Target=Vec<u64>>) -> u64 {
+///     *x.ensure(|v| !v.is_empty(), |v| v.push(7)).first().unwrap()
+/// }
+///
+/// assert_eq!(load(RwLock::new(None)), 6);
+/// assert_eq!(load(&RwLock::new(Some("already".into()))), 7);
+/// assert_eq!(load(RwApiWrapperOwned(None)), 6);
+///
+/// assert_eq!(non_empty(RwLock::new(vec![])), 7);
+/// assert_eq!(non_empty(&mut RwApiWrapperOwned(vec![1])), 1);
+/// ```
+pub trait UpgradableReadApiExt: UpgradableReadApi
+{
+    /// Makes sure `pred` holds for the guarded data, calling `fix` on it otherwise,
+    /// and returns a read guard.
+    fn ensure<P, F>(&mut self, pred: P, fix: F) -> DowngradedGuard<'_, Self>
+        where
+            P: FnOnce(&Self::Target) -> bool,
+            F: FnOnce(&mut Self::Target);
+
+    /// Replaces the guarded data with the value returned by `init`,
+    /// unless it's ready according to `is_ready`, and returns a read guard.
+    fn get_or_init_with<P, F>(&mut self, is_ready: P, init: F) -> DowngradedGuard<'_, Self>
+        where
+            Self::Target: Sized,
+            P: FnOnce(&Self::Target) -> bool,
+            F: FnOnce(&Self::Target) -> Self::Target;
+}
+
+/// Swaps the data guarded by `a` and `b` without deadlocking.
+///
+/// The locks are always acquired in the order of their [`LockIdentityApi::lock_addr`].
+///
+/// # Panics
+///
+/// Panics if `a` and `b` are views of the same lock,
+/// such as two [`Lensed`](crate::Lensed) projections of it,
+/// as swapping them would require locking it twice.
+///
+/// # Example
+///
+/// ```rust
+/// use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use parking_lot::RwLock;
+/// use read_write_api::{swap, Lensed, ReadApi, RwApiWrapperOwned};
+///
+/// let (a, b) = (RwLock::new(1), RwLock::new(2));
+/// swap(&mut &a, &mut &b);
+/// assert_eq!((*a.read(), *b.read()), (2, 1));
+///
+/// let mut wrapper = RwApiWrapperOwned(3);
+/// swap(&mut &a, &mut wrapper);
+/// assert_eq!((*a.read(), wrapper.0), (3, 2));
+///
+/// let pair = RwLock::new((1, 2));
+/// let mut first = Lensed::from_fns(&pair, |p| &p.0, |p| &mut p.0);
+/// let mut second = Lensed::from_fns(&pair, |p| &p.1, |p| &mut p.1);
+/// assert!(catch_unwind(AssertUnwindSafe(|| swap(&mut first, &mut second))).is_err());
+/// assert_eq!(*pair.read(), (1, 2));
+/// ```
+pub fn swap<A, B>(a: &mut A, b: &mut B)
+    where
+        A: WriteApi + LockIdentityApi + ?Sized,
+        A::Target: Sized,
+        B: WriteApi<Target=A::Target> + LockIdentityApi + ?Sized
+{
+    match a.lock_addr().cmp(&b.lock_addr()) {
+        Ordering::Less => {
+            let mut a = a.write();
+            mem::swap(&mut *a, &mut *b.write())
+        }
+        Ordering::Greater => {
+            let mut b = b.write();
+            mem::swap(&mut *a.write(), &mut *b)
+        }
+        Ordering::Equal => panic!("can't swap the data of two views of the same lock"),
+    }
+}
@@ -1,3 +1,16 @@
+mod any;
 mod auto;
+mod dynamic;
+mod emulated;
+mod ext;
+mod iter;
+mod lens;
+mod map;
 mod parking_lot;
+mod poison;
+mod ref_cell;
+mod scoped;
+mod shared;
+mod split;
+mod std_sync;
 mod wrappers;
\ No newline at end of file
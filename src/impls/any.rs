@@ -0,0 +1,897 @@
+use {
+    crate::{
+        AnyGuard,
+        AnyRwApi,
+        ClearPoisonApi,
+        DowngradableWriteApi,
+        DowngradableWriteGuard,
+        GuardedTarget,
+        LockIdentityApi,
+        LockStateApi,
+        MapReadGuard,
+        MapWriteGuard,
+        PoisonApi,
+        ReadApi,
+        RecursiveReadApi,
+        SharedDowngradableWriteApi,
+        SharedUpgradableReadApi,
+        SharedWriteApi,
+        TimedReadApi,
+        TimedUpgradableReadApi,
+        TimedWriteApi,
+        TryLockError,
+        TryReadApi,
+        TryUpgradableReadApi,
+        TryWriteApi,
+        UpgradableReadApi,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::time::{Duration, Instant},
+};
+
+impl<L: GuardedTarget, S: GuardedTarget<Target=L::Target>> GuardedTarget for AnyRwApi<L, S> {
+    type Target = L::Target;
+}
+
+impl<L: ReadApi, S: ReadApi<Target=L::Target>> ReadApi for AnyRwApi<L, S>
+{
+    type ReadGuard<'a> = AnyGuard<L::ReadGuard<'a>, S::ReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Self::ReadGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.read()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.read()),
+        }
+    }
+}
+
+impl<L: WriteApi, S: WriteApi<Target=L::Target>> WriteApi for AnyRwApi<L, S>
+{
+    type WriteGuard<'a> = AnyGuard<L::WriteGuard<'a>, S::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.write()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.write()),
+        }
+    }
+}
+
+impl<L, S> UpgradableReadApi for AnyRwApi<L, S>
+    where
+        L: UpgradableReadApi,
+        S: UpgradableReadApi<Target=L::Target>
+{
+    type UpgradableReadGuard<'a> = AnyGuard<L::UpgradableReadGuard<'a>, S::UpgradableReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.upgradable_read()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.upgradable_read()),
+        }
+    }
+}
+
+impl<L, S> DowngradableWriteApi for AnyRwApi<L, S>
+    where
+        L: DowngradableWriteApi,
+        S: DowngradableWriteApi<Target=L::Target>
+{
+    type DowngradableWriteGuard<'a> = AnyGuard<L::DowngradableWriteGuard<'a>, S::DowngradableWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.downgradable_write()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.downgradable_write()),
+        }
+    }
+}
+
+impl<L: TryReadApi, S: TryReadApi<Target=L::Target>> TryReadApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_read().map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_read().map(AnyGuard::Shared),
+        }
+    }
+}
+
+impl<L: TryWriteApi, S: TryWriteApi<Target=L::Target>> TryWriteApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_write().map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_write().map(AnyGuard::Shared),
+        }
+    }
+}
+
+impl<L, S> TryUpgradableReadApi for AnyRwApi<L, S>
+    where
+        L: TryUpgradableReadApi,
+        S: TryUpgradableReadApi<Target=L::Target>
+{
+    #[inline]
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_upgradable_read().map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_upgradable_read().map(AnyGuard::Shared),
+        }
+    }
+}
+
+impl<L: TimedReadApi, S: TimedReadApi<Target=L::Target>> TimedReadApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_read_for(timeout).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_read_for(timeout).map(AnyGuard::Shared),
+        }
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_read_until(deadline).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_read_until(deadline).map(AnyGuard::Shared),
+        }
+    }
+}
+
+impl<L: TimedWriteApi, S: TimedWriteApi<Target=L::Target>> TimedWriteApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_write_for(timeout).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_write_for(timeout).map(AnyGuard::Shared),
+        }
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_write_until(deadline).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_write_until(deadline).map(AnyGuard::Shared),
+        }
+    }
+}
+
+impl<L, S> TimedUpgradableReadApi for AnyRwApi<L, S>
+    where
+        L: TimedUpgradableReadApi,
+        S: TimedUpgradableReadApi<Target=L::Target>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        match self {
+            Self::Local(inner) => inner.try_upgradable_read_for(timeout).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_upgradable_read_for(timeout).map(AnyGuard::Shared),
+        }
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        match self {
+            Self::Local(inner) => inner.try_upgradable_read_until(deadline).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_upgradable_read_until(deadline).map(AnyGuard::Shared),
+        }
+    }
+}
+
+impl<L, S> RecursiveReadApi for AnyRwApi<L, S>
+    where
+        L: RecursiveReadApi,
+        S: RecursiveReadApi<Target=L::Target>
+{
+    #[inline]
+    fn read_recursive(&self) -> Self::ReadGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.read_recursive()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.read_recursive()),
+        }
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_read_recursive().map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_read_recursive().map(AnyGuard::Shared),
+        }
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_read_recursive_for(timeout).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_read_recursive_for(timeout).map(AnyGuard::Shared),
+        }
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        match self {
+            Self::Local(inner) => inner.try_read_recursive_until(deadline).map(AnyGuard::Local),
+            Self::Shared(inner) => inner.try_read_recursive_until(deadline).map(AnyGuard::Shared),
+        }
+    }
+}
+
+impl<L: SharedWriteApi, S: SharedWriteApi<Target=L::Target>> SharedWriteApi for AnyRwApi<L, S>
+{
+    type SharedWriteGuard<'a> = AnyGuard<L::SharedWriteGuard<'a>, S::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> Self::SharedWriteGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.write_shared()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.write_shared()),
+        }
+    }
+}
+
+impl<L, S> SharedUpgradableReadApi for AnyRwApi<L, S>
+    where
+        L: SharedUpgradableReadApi,
+        S: SharedUpgradableReadApi<Target=L::Target>
+{
+    type SharedUpgradableReadGuard<'a> = AnyGuard<
+        L::SharedUpgradableReadGuard<'a>,
+        S::SharedUpgradableReadGuard<'a>,
+    >
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read_shared(&self) -> Self::SharedUpgradableReadGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.upgradable_read_shared()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.upgradable_read_shared()),
+        }
+    }
+}
+
+impl<L, S> SharedDowngradableWriteApi for AnyRwApi<L, S>
+    where
+        L: SharedDowngradableWriteApi,
+        S: SharedDowngradableWriteApi<Target=L::Target>
+{
+    type SharedDowngradableWriteGuard<'a> = AnyGuard<
+        L::SharedDowngradableWriteGuard<'a>,
+        S::SharedDowngradableWriteGuard<'a>,
+    >
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write_shared(&self) -> Self::SharedDowngradableWriteGuard<'_> {
+        match self {
+            Self::Local(inner) => AnyGuard::Local(inner.downgradable_write_shared()),
+            Self::Shared(inner) => AnyGuard::Shared(inner.downgradable_write_shared()),
+        }
+    }
+}
+
+impl<L: GuardedTarget, S: GuardedTarget<Target=L::Target>> GuardedTarget for &AnyRwApi<L, S> {
+    type Target = L::Target;
+}
+
+impl<L: ReadApi, S: ReadApi<Target=L::Target>> ReadApi for &AnyRwApi<L, S>
+{
+    type ReadGuard<'a> = AnyGuard<L::ReadGuard<'a>, S::ReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Self::ReadGuard<'_> {
+        (**self).read()
+    }
+}
+
+impl<L: TryReadApi, S: TryReadApi<Target=L::Target>> TryReadApi for &AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read()
+    }
+}
+
+impl<L: TimedReadApi, S: TimedReadApi<Target=L::Target>> TimedReadApi for &AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_until(deadline)
+    }
+}
+
+impl<L, S> RecursiveReadApi for &AnyRwApi<L, S>
+    where
+        L: RecursiveReadApi,
+        S: RecursiveReadApi<Target=L::Target>
+{
+    #[inline]
+    fn read_recursive(&self) -> Self::ReadGuard<'_> {
+        (**self).read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_until(deadline)
+    }
+}
+
+impl<L: SharedWriteApi, S: SharedWriteApi<Target=L::Target>> WriteApi for &AnyRwApi<L, S>
+{
+    type WriteGuard<'a> = AnyGuard<L::SharedWriteGuard<'a>, S::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        self.write_shared()
+    }
+}
+
+impl<L, S> UpgradableReadApi for &AnyRwApi<L, S>
+    where
+        L: SharedUpgradableReadApi,
+        S: SharedUpgradableReadApi<Target=L::Target>
+{
+    type UpgradableReadGuard<'a> = AnyGuard<
+        L::SharedUpgradableReadGuard<'a>,
+        S::SharedUpgradableReadGuard<'a>,
+    >
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        self.upgradable_read_shared()
+    }
+}
+
+impl<L, S> DowngradableWriteApi for &AnyRwApi<L, S>
+    where
+        L: SharedDowngradableWriteApi,
+        S: SharedDowngradableWriteApi<Target=L::Target>
+{
+    type DowngradableWriteGuard<'a> = AnyGuard<
+        L::SharedDowngradableWriteGuard<'a>,
+        S::SharedDowngradableWriteGuard<'a>,
+    >
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        self.downgradable_write_shared()
+    }
+}
+
+impl<L: GuardedTarget, S: GuardedTarget<Target=L::Target>> GuardedTarget for &mut AnyRwApi<L, S> {
+    type Target = L::Target;
+}
+
+impl<L: ReadApi, S: ReadApi<Target=L::Target>> ReadApi for &mut AnyRwApi<L, S>
+{
+    type ReadGuard<'a> = AnyGuard<L::ReadGuard<'a>, S::ReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Self::ReadGuard<'_> {
+        (**self).read()
+    }
+}
+
+impl<L: TryReadApi, S: TryReadApi<Target=L::Target>> TryReadApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read()
+    }
+}
+
+impl<L: TimedReadApi, S: TimedReadApi<Target=L::Target>> TimedReadApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_until(deadline)
+    }
+}
+
+impl<L, S> RecursiveReadApi for &mut AnyRwApi<L, S>
+    where
+        L: RecursiveReadApi,
+        S: RecursiveReadApi<Target=L::Target>
+{
+    #[inline]
+    fn read_recursive(&self) -> Self::ReadGuard<'_> {
+        (**self).read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_until(deadline)
+    }
+}
+
+impl<L: WriteApi, S: WriteApi<Target=L::Target>> WriteApi for &mut AnyRwApi<L, S>
+{
+    type WriteGuard<'a> = AnyGuard<L::WriteGuard<'a>, S::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        (**self).write()
+    }
+}
+
+impl<L, S> UpgradableReadApi for &mut AnyRwApi<L, S>
+    where
+        L: UpgradableReadApi,
+        S: UpgradableReadApi<Target=L::Target>
+{
+    type UpgradableReadGuard<'a> = AnyGuard<L::UpgradableReadGuard<'a>, S::UpgradableReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        (**self).upgradable_read()
+    }
+}
+
+impl<L, S> DowngradableWriteApi for &mut AnyRwApi<L, S>
+    where
+        L: DowngradableWriteApi,
+        S: DowngradableWriteApi<Target=L::Target>
+{
+    type DowngradableWriteGuard<'a> = AnyGuard<L::DowngradableWriteGuard<'a>, S::DowngradableWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        (**self).downgradable_write()
+    }
+}
+
+impl<L: TryWriteApi, S: TryWriteApi<Target=L::Target>> TryWriteApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        (**self).try_write()
+    }
+}
+
+impl<L, S> TryUpgradableReadApi for &mut AnyRwApi<L, S>
+    where
+        L: TryUpgradableReadApi,
+        S: TryUpgradableReadApi<Target=L::Target>
+{
+    #[inline]
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError> {
+        (**self).try_upgradable_read()
+    }
+}
+
+impl<L: TimedWriteApi, S: TimedWriteApi<Target=L::Target>> TimedWriteApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        (**self).try_write_for(timeout)
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        (**self).try_write_until(deadline)
+    }
+}
+
+impl<L, S> TimedUpgradableReadApi for &mut AnyRwApi<L, S>
+    where
+        L: TimedUpgradableReadApi,
+        S: TimedUpgradableReadApi<Target=L::Target>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        (**self).try_upgradable_read_for(timeout)
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        (**self).try_upgradable_read_until(deadline)
+    }
+}
+
+impl<L: PoisonApi, S: PoisonApi> PoisonApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        match self {
+            Self::Local(inner) => inner.is_poisoned(),
+            Self::Shared(inner) => inner.is_poisoned(),
+        }
+    }
+}
+
+impl<L: ClearPoisonApi, S: ClearPoisonApi> ClearPoisonApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn clear_poison(&self) {
+        match self {
+            Self::Local(inner) => inner.clear_poison(),
+            Self::Shared(inner) => inner.clear_poison(),
+        }
+    }
+}
+
+impl<L: LockStateApi, S: LockStateApi> LockStateApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        match self {
+            Self::Local(inner) => inner.is_locked(),
+            Self::Shared(inner) => inner.is_locked(),
+        }
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        match self {
+            Self::Local(inner) => inner.is_locked_exclusive(),
+            Self::Shared(inner) => inner.is_locked_exclusive(),
+        }
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        match self {
+            Self::Local(inner) => inner.reader_count(),
+            Self::Shared(inner) => inner.reader_count(),
+        }
+    }
+}
+
+impl<L: PoisonApi, S: PoisonApi> PoisonApi for &AnyRwApi<L, S>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        (**self).is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi, S: ClearPoisonApi> ClearPoisonApi for &AnyRwApi<L, S>
+{
+    #[inline]
+    fn clear_poison(&self) {
+        (**self).clear_poison()
+    }
+}
+
+impl<L: LockStateApi, S: LockStateApi> LockStateApi for &AnyRwApi<L, S>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        (**self).is_locked()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        (**self).is_locked_exclusive()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        (**self).reader_count()
+    }
+}
+
+impl<L: PoisonApi, S: PoisonApi> PoisonApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        (**self).is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi, S: ClearPoisonApi> ClearPoisonApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn clear_poison(&self) {
+        (**self).clear_poison()
+    }
+}
+
+impl<L: LockStateApi, S: LockStateApi> LockStateApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        (**self).is_locked()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        (**self).is_locked_exclusive()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        (**self).reader_count()
+    }
+}
+
+impl<L, S> UpgradableReadGuard for AnyGuard<L, S>
+    where
+        L: UpgradableReadGuard,
+        S: UpgradableReadGuard<Target=L::Target>
+{
+    type UpgradeResult = AnyGuard<L::UpgradeResult, S::UpgradeResult>;
+    type UpgradeToDowngradableResult = AnyGuard<L::UpgradeToDowngradableResult, S::UpgradeToDowngradableResult>;
+
+    #[inline]
+    fn upgrade(self) -> Self::UpgradeResult {
+        match self {
+            Self::Local(guard) => AnyGuard::Local(guard.upgrade()),
+            Self::Shared(guard) => AnyGuard::Shared(guard.upgrade()),
+        }
+    }
+
+    #[inline]
+    fn try_upgrade(self) -> Result<Self::UpgradeResult, Self> {
+        match self {
+            Self::Local(guard) => match guard.try_upgrade() {
+                Ok(guard) => Ok(AnyGuard::Local(guard)),
+                Err(guard) => Err(Self::Local(guard)),
+            },
+            Self::Shared(guard) => match guard.try_upgrade() {
+                Ok(guard) => Ok(AnyGuard::Shared(guard)),
+                Err(guard) => Err(Self::Shared(guard)),
+            },
+        }
+    }
+
+    #[inline]
+    fn try_upgrade_for(self, timeout: Duration) -> Result<Self::UpgradeResult, Self> {
+        match self {
+            Self::Local(guard) => match guard.try_upgrade_for(timeout) {
+                Ok(guard) => Ok(AnyGuard::Local(guard)),
+                Err(guard) => Err(Self::Local(guard)),
+            },
+            Self::Shared(guard) => match guard.try_upgrade_for(timeout) {
+                Ok(guard) => Ok(AnyGuard::Shared(guard)),
+                Err(guard) => Err(Self::Shared(guard)),
+            },
+        }
+    }
+
+    #[inline]
+    fn try_upgrade_until(self, deadline: Instant) -> Result<Self::UpgradeResult, Self> {
+        match self {
+            Self::Local(guard) => match guard.try_upgrade_until(deadline) {
+                Ok(guard) => Ok(AnyGuard::Local(guard)),
+                Err(guard) => Err(Self::Local(guard)),
+            },
+            Self::Shared(guard) => match guard.try_upgrade_until(deadline) {
+                Ok(guard) => Ok(AnyGuard::Shared(guard)),
+                Err(guard) => Err(Self::Shared(guard)),
+            },
+        }
+    }
+
+    #[inline]
+    fn upgrade_to_downgradable(self) -> Self::UpgradeToDowngradableResult {
+        match self {
+            Self::Local(guard) => AnyGuard::Local(guard.upgrade_to_downgradable()),
+            Self::Shared(guard) => AnyGuard::Shared(guard.upgrade_to_downgradable()),
+        }
+    }
+
+    #[inline]
+    fn downgrade(self) -> <Self::UpgradeToDowngradableResult as DowngradableWriteGuard>::DowngradeResult {
+        match self {
+            Self::Local(guard) => AnyGuard::Local(guard.downgrade()),
+            Self::Shared(guard) => AnyGuard::Shared(guard.downgrade()),
+        }
+    }
+
+    #[inline]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where F: FnOnce(&mut L::Target) -> Ret
+    {
+        match self {
+            Self::Local(guard) => guard.with_upgraded(f),
+            Self::Shared(guard) => guard.with_upgraded(f),
+        }
+    }
+
+    #[inline]
+    fn try_with_upgraded<Ret, F>(&mut self, f: F) -> Option<Ret>
+        where F: FnOnce(&mut L::Target) -> Ret
+    {
+        match self {
+            Self::Local(guard) => guard.try_with_upgraded(f),
+            Self::Shared(guard) => guard.try_with_upgraded(f),
+        }
+    }
+
+    #[inline]
+    fn try_with_upgraded_for<Ret, F>(&mut self, timeout: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut L::Target) -> Ret
+    {
+        match self {
+            Self::Local(guard) => guard.try_with_upgraded_for(timeout, f),
+            Self::Shared(guard) => guard.try_with_upgraded_for(timeout, f),
+        }
+    }
+
+    #[inline]
+    fn try_with_upgraded_until<Ret, F>(&mut self, deadline: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut L::Target) -> Ret
+    {
+        match self {
+            Self::Local(guard) => guard.try_with_upgraded_until(deadline, f),
+            Self::Shared(guard) => guard.try_with_upgraded_until(deadline, f),
+        }
+    }
+}
+
+impl<L, S> DowngradableWriteGuard for AnyGuard<L, S>
+    where
+        L: DowngradableWriteGuard,
+        S: DowngradableWriteGuard<Target=L::Target>
+{
+    type DowngradeResult = AnyGuard<L::DowngradeResult, S::DowngradeResult>;
+    type DowngradeToUpgradableResult = AnyGuard<L::DowngradeToUpgradableResult, S::DowngradeToUpgradableResult>;
+
+    #[inline]
+    fn downgrade(self) -> Self::DowngradeResult {
+        match self {
+            Self::Local(guard) => AnyGuard::Local(guard.downgrade()),
+            Self::Shared(guard) => AnyGuard::Shared(guard.downgrade()),
+        }
+    }
+
+    #[inline]
+    fn downgrade_to_upgradable(self) -> Self::DowngradeToUpgradableResult {
+        match self {
+            Self::Local(guard) => AnyGuard::Local(guard.downgrade_to_upgradable()),
+            Self::Shared(guard) => AnyGuard::Shared(guard.downgrade_to_upgradable()),
+        }
+    }
+}
+
+impl<'a, L, S> MapReadGuard<'a> for AnyGuard<L, S>
+    where
+        L: MapReadGuard<'a>,
+        S: MapReadGuard<'a, Target=L::Target>
+{
+    type Mapped<U: ?Sized + 'a> = AnyGuard<L::Mapped<U>, S::Mapped<U>>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> Self::Mapped<U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&L::Target) -> &U
+    {
+        match s {
+            Self::Local(guard) => AnyGuard::Local(MapReadGuard::map(guard, f)),
+            Self::Shared(guard) => AnyGuard::Shared(MapReadGuard::map(guard, f)),
+        }
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<Self::Mapped<U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&L::Target) -> Option<&U>
+    {
+        match s {
+            Self::Local(guard) => MapReadGuard::try_map(guard, f).map(AnyGuard::Local).map_err(Self::Local),
+            Self::Shared(guard) => MapReadGuard::try_map(guard, f).map(AnyGuard::Shared).map_err(Self::Shared),
+        }
+    }
+}
+
+impl<'a, L, S> MapWriteGuard<'a> for AnyGuard<L, S>
+    where
+        L: MapWriteGuard<'a>,
+        S: MapWriteGuard<'a, Target=L::Target>
+{
+    type Mapped<U: ?Sized + 'a> = AnyGuard<L::Mapped<U>, S::Mapped<U>>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> Self::Mapped<U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut L::Target) -> &mut U
+    {
+        match s {
+            Self::Local(guard) => AnyGuard::Local(MapWriteGuard::map(guard, f)),
+            Self::Shared(guard) => AnyGuard::Shared(MapWriteGuard::map(guard, f)),
+        }
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<Self::Mapped<U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut L::Target) -> Option<&mut U>
+    {
+        match s {
+            Self::Local(guard) => MapWriteGuard::try_map(guard, f).map(AnyGuard::Local).map_err(Self::Local),
+            Self::Shared(guard) => MapWriteGuard::try_map(guard, f).map(AnyGuard::Shared).map_err(Self::Shared),
+        }
+    }
+}
+
+impl<L: LockIdentityApi, S: LockIdentityApi> LockIdentityApi for AnyRwApi<L, S>
+{
+    #[inline]
+    fn lock_addr(&self) -> *const () {
+        match self {
+            Self::Local(inner) => inner.lock_addr(),
+            Self::Shared(inner) => inner.lock_addr(),
+        }
+    }
+}
+
+impl<L: LockIdentityApi, S: LockIdentityApi> LockIdentityApi for &AnyRwApi<L, S>
+{
+    #[inline]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<L: LockIdentityApi, S: LockIdentityApi> LockIdentityApi for &mut AnyRwApi<L, S>
+{
+    #[inline]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
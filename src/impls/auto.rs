@@ -1,4 +1,20 @@
-use crate::{DowngradableWriteApi, ReadApi, RwApi, UpgradableReadApi, WriteApi};
+use crate::{
+    BasicRwApi,
+    DowngradableWriteApi,
+    OwnedDowngradableWriteApi,
+    OwnedReadApi,
+    OwnedRwApi,
+    OwnedUpgradableReadApi,
+    OwnedWriteApi,
+    ReadApi,
+    RwApi,
+    SharedDowngradableWriteApi,
+    SharedRwApi,
+    SharedUpgradableReadApi,
+    SharedWriteApi,
+    UpgradableReadApi,
+    WriteApi,
+};
 
 impl<T: ?Sized, R: ?Sized> RwApi for T
     where
@@ -6,4 +22,25 @@ impl<T: ?Sized, R: ?Sized> RwApi for T
         + WriteApi<Target=R>
         + DowngradableWriteApi<Target=R>
         + UpgradableReadApi<Target=R>
+{}
+
+impl<T: ?Sized, R: ?Sized> BasicRwApi for T
+    where
+        Self: ReadApi<Target=R> + WriteApi<Target=R>
+{}
+
+impl<T: ?Sized, R: ?Sized> OwnedRwApi for T
+    where
+        Self: OwnedReadApi<Target=R>
+        + OwnedWriteApi<Target=R>
+        + OwnedDowngradableWriteApi<Target=R>
+        + OwnedUpgradableReadApi<Target=R>
+{}
+
+impl<T: ?Sized, R: ?Sized> SharedRwApi for T
+    where
+        Self: ReadApi<Target=R>
+        + SharedWriteApi<Target=R>
+        + SharedDowngradableWriteApi<Target=R>
+        + SharedUpgradableReadApi<Target=R>
 {}
\ No newline at end of file
@@ -0,0 +1,92 @@
+use crate::{
+    dynamic::{DynDowngradableGuard, DynReadGuard, DynUpgradableGuard, DynWriteGuard},
+    DowngradableWriteApi,
+    DowngradableWriteGuard,
+    DynDowngradableWriteApi,
+    DynDowngradableWriteGuard,
+    DynReadApi,
+    DynRwApi,
+    DynUpgradableReadApi,
+    DynUpgradableReadGuard,
+    DynWriteApi,
+    ReadApi,
+    UpgradableReadApi,
+    UpgradableReadGuard,
+    WriteApi,
+};
+
+impl<T: ?Sized, R: ?Sized> DynRwApi for T
+    where
+        Self: DynReadApi<Target=R>
+        + DynWriteApi<Target=R>
+        + DynDowngradableWriteApi<Target=R>
+        + DynUpgradableReadApi<Target=R>
+{}
+
+impl<T: ReadApi + ?Sized> DynReadApi for T
+{
+    #[inline]
+    fn read_dyn(&self) -> DynReadGuard<'_, T::Target> {
+        Box::new(self.read())
+    }
+}
+
+impl<T: WriteApi + ?Sized> DynWriteApi for T
+{
+    #[inline]
+    fn write_dyn(&mut self) -> DynWriteGuard<'_, T::Target> {
+        Box::new(self.write())
+    }
+}
+
+impl<T: UpgradableReadApi + ?Sized> DynUpgradableReadApi for T
+{
+    #[inline]
+    fn upgradable_read_dyn(&mut self) -> DynUpgradableGuard<'_, T::Target> {
+        Box::new(self.upgradable_read())
+    }
+}
+
+impl<T: DowngradableWriteApi + ?Sized> DynDowngradableWriteApi for T
+{
+    #[inline]
+    fn downgradable_write_dyn(&mut self) -> DynDowngradableGuard<'_, T::Target> {
+        Box::new(self.downgradable_write())
+    }
+}
+
+impl<'a, G> DynUpgradableReadGuard<'a> for G
+    where
+        G: UpgradableReadGuard + 'a,
+        G::UpgradeResult: 'a,
+        G::UpgradeToDowngradableResult: 'a,
+        <G::UpgradeToDowngradableResult as DowngradableWriteGuard>::DowngradeResult: 'a
+{
+    #[inline]
+    fn upgrade_dyn(self: Box<Self>) -> DynWriteGuard<'a, G::Target> {
+        Box::new((*self).upgrade())
+    }
+
+    #[inline]
+    fn upgrade_to_downgradable_dyn(self: Box<Self>) -> DynDowngradableGuard<'a, G::Target> {
+        Box::new((*self).upgrade_to_downgradable())
+    }
+}
+
+impl<'a, G> DynDowngradableWriteGuard<'a> for G
+    where
+        G: DowngradableWriteGuard + 'a,
+        G::DowngradeResult: 'a,
+        G::DowngradeToUpgradableResult: 'a,
+        <G::DowngradeToUpgradableResult as UpgradableReadGuard>::UpgradeResult: 'a
+{
+    #[inline]
+    fn downgrade_dyn(self: Box<Self>) -> DynReadGuard<'a, G::Target> {
+        Box::new((*self).downgrade())
+    }
+
+    #[inline]
+    fn downgrade_to_upgradable_dyn(self: Box<Self>) -> DynUpgradableGuard<'a, G::Target> {
+        Box::new((*self).downgrade_to_upgradable())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,656 @@
+use {
+    crate::{
+        ClearPoisonApi,
+        DowngradableWriteApi,
+        DowngradableWriteGuard,
+        Emulated,
+        EmulatedReadGuard,
+        EmulatedWriteGuard,
+        GuardedTarget,
+        IntoInnerApi,
+        LockIdentityApi,
+        LockStateApi,
+        NewApi,
+        PoisonApi,
+        ReadApi,
+        SharedDowngradableWriteApi,
+        SharedUpgradableReadApi,
+        SharedWriteApi,
+        TimedReadApi,
+        TimedUpgradableReadApi,
+        TimedWriteApi,
+        TryLockError,
+        TryReadApi,
+        TryUpgradableReadApi,
+        TryWriteApi,
+        UpgradableReadApi,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::{
+        ops::DerefMut,
+        time::{Duration, Instant},
+    },
+};
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for Emulated<L> {
+    type Target = L::Target;
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for Emulated<L>
+{
+    type ReadGuard<'a> = L::ReadGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        self.0.read()
+    }
+}
+
+impl<L: WriteApi + ?Sized> WriteApi for Emulated<L>
+{
+    type WriteGuard<'a> = L::WriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write(&mut self) -> L::WriteGuard<'_> {
+        self.0.write()
+    }
+}
+
+impl<L: WriteApi + ?Sized> UpgradableReadApi for Emulated<L>
+{
+    type UpgradableReadGuard<'a> = EmulatedReadGuard<L::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        EmulatedReadGuard::new(self.0.write())
+    }
+}
+
+impl<L: WriteApi + ?Sized> DowngradableWriteApi for Emulated<L>
+{
+    type DowngradableWriteGuard<'a> = EmulatedWriteGuard<L::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        EmulatedWriteGuard::new(self.0.write())
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedWriteApi for Emulated<L>
+{
+    type SharedWriteGuard<'a> = L::SharedWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write_shared(&self) -> L::SharedWriteGuard<'_> {
+        self.0.write_shared()
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedUpgradableReadApi for Emulated<L>
+{
+    type SharedUpgradableReadGuard<'a> = EmulatedReadGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read_shared(&self) -> Self::SharedUpgradableReadGuard<'_> {
+        EmulatedReadGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedDowngradableWriteApi for Emulated<L>
+{
+    type SharedDowngradableWriteGuard<'a> = EmulatedWriteGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write_shared(&self) -> Self::SharedDowngradableWriteGuard<'_> {
+        EmulatedWriteGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: TryReadApi + ?Sized> TryReadApi for Emulated<L>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read()
+    }
+}
+
+impl<L: TryWriteApi + ?Sized> TryWriteApi for Emulated<L>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<L::WriteGuard<'_>, TryLockError> {
+        self.0.try_write()
+    }
+}
+
+impl<L: TryWriteApi + ?Sized> TryUpgradableReadApi for Emulated<L>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError> {
+        self.0.try_write().map(EmulatedReadGuard::new)
+    }
+}
+
+impl<L: TimedReadApi + ?Sized> TimedReadApi for Emulated<L>
+{
+    #[inline(always)]
+    fn try_read_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_until(deadline)
+    }
+}
+
+impl<L: TimedWriteApi + ?Sized> TimedWriteApi for Emulated<L>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<L::WriteGuard<'_>, TryLockError> {
+        self.0.try_write_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<L::WriteGuard<'_>, TryLockError> {
+        self.0.try_write_until(deadline)
+    }
+}
+
+impl<L: TimedWriteApi + ?Sized> TimedUpgradableReadApi for Emulated<L>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        self.0.try_write_for(timeout).map(EmulatedReadGuard::new)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        self.0.try_write_until(deadline).map(EmulatedReadGuard::new)
+    }
+}
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for &Emulated<L> {
+    type Target = L::Target;
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for &Emulated<L>
+{
+    type ReadGuard<'a> = L::ReadGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        self.0.read()
+    }
+}
+
+impl<L: TryReadApi + ?Sized> TryReadApi for &Emulated<L>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read()
+    }
+}
+
+impl<L: TimedReadApi + ?Sized> TimedReadApi for &Emulated<L>
+{
+    #[inline(always)]
+    fn try_read_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_until(deadline)
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> WriteApi for &Emulated<L>
+{
+    type WriteGuard<'a> = L::SharedWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write(&mut self) -> L::SharedWriteGuard<'_> {
+        self.0.write_shared()
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> UpgradableReadApi for &Emulated<L>
+{
+    type UpgradableReadGuard<'a> = EmulatedReadGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        EmulatedReadGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> DowngradableWriteApi for &Emulated<L>
+{
+    type DowngradableWriteGuard<'a> = EmulatedWriteGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        EmulatedWriteGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedWriteApi for &Emulated<L>
+{
+    type SharedWriteGuard<'a> = L::SharedWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write_shared(&self) -> L::SharedWriteGuard<'_> {
+        self.0.write_shared()
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedUpgradableReadApi for &Emulated<L>
+{
+    type SharedUpgradableReadGuard<'a> = EmulatedReadGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read_shared(&self) -> Self::SharedUpgradableReadGuard<'_> {
+        EmulatedReadGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedDowngradableWriteApi for &Emulated<L>
+{
+    type SharedDowngradableWriteGuard<'a> = EmulatedWriteGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write_shared(&self) -> Self::SharedDowngradableWriteGuard<'_> {
+        EmulatedWriteGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for &mut Emulated<L> {
+    type Target = L::Target;
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for &mut Emulated<L>
+{
+    type ReadGuard<'a> = L::ReadGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        self.0.read()
+    }
+}
+
+impl<L: TryReadApi + ?Sized> TryReadApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read()
+    }
+}
+
+impl<L: TimedReadApi + ?Sized> TimedReadApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn try_read_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_until(deadline)
+    }
+}
+
+impl<L: WriteApi + ?Sized> WriteApi for &mut Emulated<L>
+{
+    type WriteGuard<'a> = L::WriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write(&mut self) -> L::WriteGuard<'_> {
+        self.0.write()
+    }
+}
+
+impl<L: WriteApi + ?Sized> UpgradableReadApi for &mut Emulated<L>
+{
+    type UpgradableReadGuard<'a> = EmulatedReadGuard<L::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        EmulatedReadGuard::new(self.0.write())
+    }
+}
+
+impl<L: WriteApi + ?Sized> DowngradableWriteApi for &mut Emulated<L>
+{
+    type DowngradableWriteGuard<'a> = EmulatedWriteGuard<L::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        EmulatedWriteGuard::new(self.0.write())
+    }
+}
+
+impl<L: TryWriteApi + ?Sized> TryWriteApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<L::WriteGuard<'_>, TryLockError> {
+        self.0.try_write()
+    }
+}
+
+impl<L: TryWriteApi + ?Sized> TryUpgradableReadApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError> {
+        self.0.try_write().map(EmulatedReadGuard::new)
+    }
+}
+
+impl<L: TimedWriteApi + ?Sized> TimedWriteApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<L::WriteGuard<'_>, TryLockError> {
+        self.0.try_write_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<L::WriteGuard<'_>, TryLockError> {
+        self.0.try_write_until(deadline)
+    }
+}
+
+impl<L: TimedWriteApi + ?Sized> TimedUpgradableReadApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        self.0.try_write_for(timeout).map(EmulatedReadGuard::new)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        self.0.try_write_until(deadline).map(EmulatedReadGuard::new)
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedWriteApi for &mut Emulated<L>
+{
+    type SharedWriteGuard<'a> = L::SharedWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write_shared(&self) -> L::SharedWriteGuard<'_> {
+        self.0.write_shared()
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedUpgradableReadApi for &mut Emulated<L>
+{
+    type SharedUpgradableReadGuard<'a> = EmulatedReadGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read_shared(&self) -> Self::SharedUpgradableReadGuard<'_> {
+        EmulatedReadGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedDowngradableWriteApi for &mut Emulated<L>
+{
+    type SharedDowngradableWriteGuard<'a> = EmulatedWriteGuard<L::SharedWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write_shared(&self) -> Self::SharedDowngradableWriteGuard<'_> {
+        EmulatedWriteGuard::new(self.0.write_shared())
+    }
+}
+
+impl<L: NewApi> NewApi for Emulated<L>
+    where L::Target: Sized
+{
+    #[inline]
+    fn from_value(value: L::Target) -> Self {
+        Self(L::from_value(value))
+    }
+}
+
+impl<L: IntoInnerApi> IntoInnerApi for Emulated<L>
+    where L::Target: Sized
+{
+    #[inline]
+    fn into_inner(self) -> L::Target {
+        self.0.into_inner()
+    }
+}
+
+impl<L: PoisonApi + ?Sized> PoisonApi for Emulated<L>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        self.0.is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi + ?Sized> ClearPoisonApi for Emulated<L>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {
+        self.0.clear_poison()
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for Emulated<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        self.0.is_locked_exclusive()
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        self.0.reader_count()
+    }
+}
+
+impl<L: PoisonApi + ?Sized> PoisonApi for &Emulated<L>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        self.0.is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi + ?Sized> ClearPoisonApi for &Emulated<L>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {
+        self.0.clear_poison()
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for &Emulated<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        self.0.is_locked_exclusive()
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        self.0.reader_count()
+    }
+}
+
+impl<L: PoisonApi + ?Sized> PoisonApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        self.0.is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi + ?Sized> ClearPoisonApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {
+        self.0.clear_poison()
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        self.0.is_locked_exclusive()
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        self.0.reader_count()
+    }
+}
+
+impl<G: DerefMut> UpgradableReadGuard for EmulatedReadGuard<G>
+{
+    type UpgradeResult = EmulatedWriteGuard<G>;
+    type UpgradeToDowngradableResult = EmulatedWriteGuard<G>;
+
+    #[inline(always)]
+    fn upgrade(self) -> EmulatedWriteGuard<G> {
+        EmulatedWriteGuard::new(self.into_inner())
+    }
+
+    #[inline(always)]
+    fn try_upgrade(self) -> Result<EmulatedWriteGuard<G>, Self> {
+        Ok(self.upgrade())
+    }
+
+    #[inline(always)]
+    fn try_upgrade_for(self, _: Duration) -> Result<EmulatedWriteGuard<G>, Self> {
+        Ok(self.upgrade())
+    }
+
+    #[inline(always)]
+    fn try_upgrade_until(self, _: Instant) -> Result<EmulatedWriteGuard<G>, Self> {
+        Ok(self.upgrade())
+    }
+
+    #[inline(always)]
+    fn upgrade_to_downgradable(self) -> EmulatedWriteGuard<G> {
+        self.upgrade()
+    }
+
+    #[inline(always)]
+    fn downgrade(self) -> Self {
+        self
+    }
+
+    #[inline(always)]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        f(self.inner_mut())
+    }
+
+    #[inline(always)]
+    fn try_with_upgraded<Ret, F>(&mut self, f: F) -> Option<Ret>
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        Some(f(self.inner_mut()))
+    }
+
+    #[inline(always)]
+    fn try_with_upgraded_for<Ret, F>(&mut self, _: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        Some(f(self.inner_mut()))
+    }
+
+    #[inline(always)]
+    fn try_with_upgraded_until<Ret, F>(&mut self, _: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        Some(f(self.inner_mut()))
+    }
+}
+
+impl<G: DerefMut> DowngradableWriteGuard for EmulatedWriteGuard<G>
+{
+    type DowngradeResult = EmulatedReadGuard<G>;
+    type DowngradeToUpgradableResult = EmulatedReadGuard<G>;
+
+    #[inline(always)]
+    fn downgrade(self) -> EmulatedReadGuard<G> {
+        EmulatedReadGuard::new(self.into_inner())
+    }
+
+    #[inline(always)]
+    fn downgrade_to_upgradable(self) -> EmulatedReadGuard<G> {
+        EmulatedReadGuard::new(self.into_inner())
+    }
+}
+
+impl<L: LockIdentityApi + ?Sized> LockIdentityApi for Emulated<L>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self.0.lock_addr()
+    }
+}
+
+impl<L: LockIdentityApi + ?Sized> LockIdentityApi for &Emulated<L>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self.0.lock_addr()
+    }
+}
+
+impl<L: LockIdentityApi + ?Sized> LockIdentityApi for &mut Emulated<L>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self.0.lock_addr()
+    }
+}
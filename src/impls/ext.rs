@@ -0,0 +1,110 @@
+use {
+    crate::{
+        DowngradableWriteGuard,
+        DowngradedGuard,
+        ReadApi,
+        ReadApiExt,
+        RwApiExt,
+        UpgradableReadApi,
+        UpgradableReadApiExt,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::mem,
+};
+
+impl<L: ReadApi + ?Sized> ReadApiExt for L
+{
+    #[inline]
+    fn cloned(&self) -> L::Target
+        where L::Target: Clone
+    {
+        L::Target::clone(&self.read())
+    }
+
+    #[inline]
+    fn copied(&self) -> L::Target
+        where L::Target: Copy
+    {
+        *self.read()
+    }
+}
+
+impl<L: WriteApi + UpgradableReadApi + ?Sized> RwApiExt for L
+{
+    #[inline]
+    fn update<F>(&mut self, f: F)
+        where
+            L::Target: Sized,
+            F: FnOnce(&L::Target) -> L::Target
+    {
+        let mut guard = self.write();
+        *guard = f(&guard)
+    }
+
+    #[inline]
+    fn replace(&mut self, value: L::Target) -> L::Target
+        where L::Target: Sized
+    {
+        mem::replace(&mut self.write(), value)
+    }
+
+    #[inline]
+    fn take(&mut self) -> L::Target
+        where L::Target: Default
+    {
+        mem::take(&mut self.write())
+    }
+
+    #[inline]
+    fn compare_and_set(&mut self, expected: &L::Target, new: L::Target) -> Result<L::Target, L::Target>
+        where L::Target: PartialEq + Sized
+    {
+        let guard = self.upgradable_read();
+        if *guard == *expected {
+            Ok(mem::replace(&mut guard.upgrade(), new))
+        } else {
+            Err(new)
+        }
+    }
+
+    #[inline]
+    fn fetch_update<F>(&mut self, f: F) -> Option<L::Target>
+        where
+            L::Target: Sized,
+            F: FnOnce(&L::Target) -> Option<L::Target>
+    {
+        let guard = self.upgradable_read();
+        let new = f(&guard)?;
+        Some(mem::replace(&mut guard.upgrade(), new))
+    }
+}
+
+impl<L: UpgradableReadApi + ?Sized> UpgradableReadApiExt for L
+{
+    #[inline]
+    fn ensure<P, F>(&mut self, pred: P, fix: F) -> DowngradedGuard<'_, L>
+        where
+            P: FnOnce(&L::Target) -> bool,
+            F: FnOnce(&mut L::Target)
+    {
+        let guard = self.upgradable_read();
+        if pred(&guard) {
+            guard.downgrade()
+        } else {
+            let mut guard = guard.upgrade_to_downgradable();
+            fix(&mut guard);
+            guard.downgrade()
+        }
+    }
+
+    #[inline]
+    fn get_or_init_with<P, F>(&mut self, is_ready: P, init: F) -> DowngradedGuard<'_, L>
+        where
+            L::Target: Sized,
+            P: FnOnce(&L::Target) -> bool,
+            F: FnOnce(&L::Target) -> L::Target
+    {
+        self.ensure(is_ready, |data| *data = init(data))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,176 @@
+use {
+    crate::{
+        DrainLocked,
+        DrainTarget,
+        GuardedIter,
+        IterReadApiExt,
+        IterUpgradableReadApiExt,
+        IterWriteApiExt,
+        ReadApi,
+        ReadIter,
+        UpgradableReadApi,
+        UpgradableReadIter,
+        WriteApi,
+        WriteIter,
+    },
+    std::{
+        collections::{
+            binary_heap,
+            btree_map,
+            btree_set,
+            hash_map,
+            hash_set,
+            vec_deque,
+            BTreeMap,
+            BTreeSet,
+            BinaryHeap,
+            HashMap,
+            HashSet,
+            VecDeque,
+        },
+        mem,
+        vec,
+    },
+};
+
+impl<L: ReadApi + ?Sized> IterReadApiExt for L
+{
+    #[inline]
+    fn read_iter<'a>(&'a self) -> ReadIter<'a, L>
+        where &'a L::Target: IntoIterator
+    {
+        // SAFETY: the iterator borrows only the guarded data.
+        unsafe { GuardedIter::new(self.read(), |data| (&*data).into_iter()) }
+    }
+}
+
+impl<L: UpgradableReadApi + ?Sized> IterUpgradableReadApiExt for L
+{
+    #[inline]
+    fn upgradable_read_iter<'a>(&'a mut self) -> UpgradableReadIter<'a, L>
+        where &'a L::Target: IntoIterator
+    {
+        // SAFETY: the iterator borrows only the guarded data.
+        unsafe { GuardedIter::new(self.upgradable_read(), |data| (&*data).into_iter()) }
+    }
+}
+
+impl<L: WriteApi + ?Sized> IterWriteApiExt for L
+{
+    #[inline]
+    fn write_iter_mut<'a>(&'a mut self) -> WriteIter<'a, L>
+        where &'a mut L::Target: IntoIterator
+    {
+        // SAFETY: the iterator borrows only the guarded data.
+        unsafe { GuardedIter::new_mut(self.write(), |data| (&mut *data).into_iter()) }
+    }
+
+    #[inline]
+    fn drain_locked(&mut self) -> DrainLocked<L::WriteGuard<'_>, <L::Target as DrainTarget>::Drain<'_>>
+        where L::Target: DrainTarget
+    {
+        // SAFETY: the iterator borrows only the guarded data.
+        unsafe { DrainLocked::new(self.write(), |data| (*data).drain_all()) }
+    }
+}
+
+impl<T> DrainTarget for Vec<T>
+{
+    type Item = T;
+    type Drain<'a> = vec::Drain<'a, T> where T: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> vec::Drain<'_, T> {
+        self.drain(..)
+    }
+}
+
+impl<T> DrainTarget for VecDeque<T>
+{
+    type Item = T;
+    type Drain<'a> = vec_deque::Drain<'a, T> where T: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> vec_deque::Drain<'_, T> {
+        self.drain(..)
+    }
+}
+
+impl<T> DrainTarget for BinaryHeap<T>
+{
+    type Item = T;
+    type Drain<'a> = binary_heap::Drain<'a, T> where T: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> binary_heap::Drain<'_, T> {
+        self.drain()
+    }
+}
+
+impl<K, V, S> DrainTarget for HashMap<K, V, S>
+{
+    type Item = (K, V);
+    type Drain<'a> = hash_map::Drain<'a, K, V> where Self: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> hash_map::Drain<'_, K, V> {
+        self.drain()
+    }
+}
+
+impl<T, S> DrainTarget for HashSet<T, S>
+{
+    type Item = T;
+    type Drain<'a> = hash_set::Drain<'a, T> where Self: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> hash_set::Drain<'_, T> {
+        self.drain()
+    }
+}
+
+impl<K, V> DrainTarget for BTreeMap<K, V>
+{
+    type Item = (K, V);
+    type Drain<'a> = btree_map::IntoIter<K, V> where Self: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> btree_map::IntoIter<K, V> {
+        mem::take(self).into_iter()
+    }
+}
+
+impl<T> DrainTarget for BTreeSet<T>
+{
+    type Item = T;
+    type Drain<'a> = btree_set::IntoIter<T> where Self: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> btree_set::IntoIter<T> {
+        mem::take(self).into_iter()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> DrainTarget for indexmap::IndexMap<K, V, S>
+{
+    type Item = (K, V);
+    type Drain<'a> = indexmap::map::Drain<'a, K, V> where Self: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> indexmap::map::Drain<'_, K, V> {
+        self.drain(..)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> DrainTarget for indexmap::IndexSet<T, S>
+{
+    type Item = T;
+    type Drain<'a> = indexmap::set::Drain<'a, T> where Self: 'a;
+
+    #[inline]
+    fn drain_all(&mut self) -> indexmap::set::Drain<'_, T> {
+        self.drain(..)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,268 @@
+use {
+    crate::{
+        DowngradableWriteApi,
+        DowngradableWriteGuard,
+        GuardedTarget,
+        Lens,
+        LensGuard,
+        Lensed,
+        LockIdentityApi,
+        MapReadGuard,
+        MapWriteGuard,
+        ReadApi,
+        UpgradableReadApi,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::time::{Duration, Instant},
+};
+
+impl<S: ?Sized, U: ?Sized, R, W> Lens<S> for (R, W)
+    where
+        R: Fn(&S) -> &U,
+        W: Fn(&mut S) -> &mut U
+{
+    type Field = U;
+
+    #[inline(always)]
+    fn get<'s>(&self, source: &'s S) -> &'s U {
+        (self.0)(source)
+    }
+
+    #[inline(always)]
+    fn get_mut<'s>(&self, source: &'s mut S) -> &'s mut U {
+        (self.1)(source)
+    }
+}
+
+impl<L: GuardedTarget, Z: Lens<L::Target>> GuardedTarget for Lensed<L, Z> {
+    type Target = Z::Field;
+}
+
+impl<L: ReadApi, Z: Lens<L::Target>> ReadApi for Lensed<L, Z>
+{
+    type ReadGuard<'a> = LensGuard<'a, L::ReadGuard<'a>, Z>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Self::ReadGuard<'_> {
+        let (inner, lens) = self.parts();
+        LensGuard::new(inner.read(), lens)
+    }
+}
+
+impl<L: WriteApi, Z: Lens<L::Target>> WriteApi for Lensed<L, Z>
+{
+    type WriteGuard<'a> = LensGuard<'a, L::WriteGuard<'a>, Z>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        let (inner, lens) = self.parts_mut();
+        LensGuard::new(inner.write(), lens)
+    }
+}
+
+impl<L: UpgradableReadApi, Z: Lens<L::Target>> UpgradableReadApi for Lensed<L, Z>
+{
+    type UpgradableReadGuard<'a> = LensGuard<'a, L::UpgradableReadGuard<'a>, Z>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        let (inner, lens) = self.parts_mut();
+        LensGuard::new(inner.upgradable_read(), lens)
+    }
+}
+
+impl<L: DowngradableWriteApi, Z: Lens<L::Target>> DowngradableWriteApi for Lensed<L, Z>
+{
+    type DowngradableWriteGuard<'a> = LensGuard<'a, L::DowngradableWriteGuard<'a>, Z>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        let (inner, lens) = self.parts_mut();
+        LensGuard::new(inner.downgradable_write(), lens)
+    }
+}
+
+// The mapping closures get `data` for any lifetime, and rustc can't prove
+// that `Z::Field` outlives all of them unless `Z: 'static`,
+// so `|data| f(lens.get(data))` is rejected and the composition goes through raw pointers.
+impl<'a, G: MapReadGuard<'a>, Z: Lens<G::Target>> MapReadGuard<'a> for LensGuard<'a, G, Z>
+{
+    type Mapped<U: ?Sized + 'a> = G::Mapped<U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> G::Mapped<U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&Z::Field) -> &U
+    {
+        let (guard, lens) = s.into_parts();
+        MapReadGuard::map(guard, |data| {
+            let field: *const Z::Field = lens.get(data);
+            // SAFETY: `Lens::get` and `f` only reborrow `data`, so does their composition.
+            unsafe { &*(f(&*field) as *const U) }
+        })
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<G::Mapped<U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&Z::Field) -> Option<&U>
+    {
+        let (guard, lens) = s.into_parts();
+        MapReadGuard::try_map(guard, |data| {
+            let field: *const Z::Field = lens.get(data);
+            // SAFETY: `Lens::get` and `f` only reborrow `data`, so does their composition.
+            unsafe { f(&*field).map(|mapped| &*(mapped as *const U)) }
+        })
+            .map_err(|guard| LensGuard::new(guard, lens))
+    }
+}
+
+impl<'a, G: MapWriteGuard<'a>, Z: Lens<G::Target>> MapWriteGuard<'a> for LensGuard<'a, G, Z>
+{
+    type Mapped<U: ?Sized + 'a> = G::Mapped<U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> G::Mapped<U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut Z::Field) -> &mut U
+    {
+        let (guard, lens) = s.into_parts();
+        MapWriteGuard::map(guard, |data| {
+            let field: *mut Z::Field = lens.get_mut(data);
+            // SAFETY: `Lens::get_mut` and `f` only reborrow `data`, so does their composition.
+            unsafe { &mut *(f(&mut *field) as *mut U) }
+        })
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<G::Mapped<U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut Z::Field) -> Option<&mut U>
+    {
+        let (guard, lens) = s.into_parts();
+        MapWriteGuard::try_map(guard, |data| {
+            let field: *mut Z::Field = lens.get_mut(data);
+            // SAFETY: `Lens::get_mut` and `f` only reborrow `data`, so does their composition.
+            unsafe { f(&mut *field).map(|mapped| &mut *(mapped as *mut U)) }
+        })
+            .map_err(|guard| LensGuard::new(guard, lens))
+    }
+}
+
+impl<'a, G: UpgradableReadGuard, Z: Lens<G::Target>> UpgradableReadGuard for LensGuard<'a, G, Z>
+{
+    type UpgradeResult = LensGuard<'a, G::UpgradeResult, Z>;
+    type UpgradeToDowngradableResult = LensGuard<'a, G::UpgradeToDowngradableResult, Z>;
+
+    #[inline]
+    fn upgrade(self) -> Self::UpgradeResult {
+        let (guard, lens) = self.into_parts();
+        LensGuard::new(guard.upgrade(), lens)
+    }
+
+    #[inline]
+    fn try_upgrade(self) -> Result<Self::UpgradeResult, Self> {
+        let (guard, lens) = self.into_parts();
+        match guard.try_upgrade() {
+            Ok(guard) => Ok(LensGuard::new(guard, lens)),
+            Err(guard) => Err(LensGuard::new(guard, lens))
+        }
+    }
+
+    #[inline]
+    fn try_upgrade_for(self, timeout: Duration) -> Result<Self::UpgradeResult, Self> {
+        let (guard, lens) = self.into_parts();
+        match guard.try_upgrade_for(timeout) {
+            Ok(guard) => Ok(LensGuard::new(guard, lens)),
+            Err(guard) => Err(LensGuard::new(guard, lens))
+        }
+    }
+
+    #[inline]
+    fn try_upgrade_until(self, deadline: Instant) -> Result<Self::UpgradeResult, Self> {
+        let (guard, lens) = self.into_parts();
+        match guard.try_upgrade_until(deadline) {
+            Ok(guard) => Ok(LensGuard::new(guard, lens)),
+            Err(guard) => Err(LensGuard::new(guard, lens))
+        }
+    }
+
+    #[inline]
+    fn upgrade_to_downgradable(self) -> Self::UpgradeToDowngradableResult {
+        let (guard, lens) = self.into_parts();
+        LensGuard::new(guard.upgrade_to_downgradable(), lens)
+    }
+
+    #[inline]
+    fn downgrade(self) -> <Self::UpgradeToDowngradableResult as DowngradableWriteGuard>::DowngradeResult {
+        let (guard, lens) = self.into_parts();
+        LensGuard::new(guard.downgrade(), lens)
+    }
+
+    #[inline]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where F: FnOnce(&mut Z::Field) -> Ret
+    {
+        let (guard, lens) = self.parts_mut();
+        guard.with_upgraded(|data| f(lens.get_mut(data)))
+    }
+
+    #[inline]
+    fn try_with_upgraded<Ret, F>(&mut self, f: F) -> Option<Ret>
+        where F: FnOnce(&mut Z::Field) -> Ret
+    {
+        let (guard, lens) = self.parts_mut();
+        guard.try_with_upgraded(|data| f(lens.get_mut(data)))
+    }
+
+    #[inline]
+    fn try_with_upgraded_for<Ret, F>(&mut self, timeout: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut Z::Field) -> Ret
+    {
+        let (guard, lens) = self.parts_mut();
+        guard.try_with_upgraded_for(timeout, |data| f(lens.get_mut(data)))
+    }
+
+    #[inline]
+    fn try_with_upgraded_until<Ret, F>(&mut self, deadline: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut Z::Field) -> Ret
+    {
+        let (guard, lens) = self.parts_mut();
+        guard.try_with_upgraded_until(deadline, |data| f(lens.get_mut(data)))
+    }
+}
+
+impl<'a, G: DowngradableWriteGuard, Z: Lens<G::Target>> DowngradableWriteGuard for LensGuard<'a, G, Z>
+{
+    type DowngradeResult = LensGuard<'a, G::DowngradeResult, Z>;
+    type DowngradeToUpgradableResult = LensGuard<'a, G::DowngradeToUpgradableResult, Z>;
+
+    #[inline]
+    fn downgrade(self) -> Self::DowngradeResult {
+        let (guard, lens) = self.into_parts();
+        LensGuard::new(guard.downgrade(), lens)
+    }
+
+    #[inline]
+    fn downgrade_to_upgradable(self) -> Self::DowngradeToUpgradableResult {
+        let (guard, lens) = self.into_parts();
+        LensGuard::new(guard.downgrade_to_upgradable(), lens)
+    }
+}
+
+impl<L: LockIdentityApi, Z> LockIdentityApi for Lensed<L, Z>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self.parts().0.lock_addr()
+    }
+}
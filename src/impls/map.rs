@@ -0,0 +1,175 @@
+use {
+    crate::{
+        DowngradedGuard,
+        EntryGuard,
+        GetOrInsertGuard,
+        MapReadGuard,
+        MapTarget,
+        MapWriteGuard,
+        MapUpgradableReadApiExt,
+        MapWriteApiExt,
+        UpgradableReadApi,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::{
+        collections::{btree_map, hash_map, BTreeMap, HashMap},
+        hash::{BuildHasher, Hash},
+    },
+};
+
+impl<K: Eq + Hash, V, S: BuildHasher> MapTarget for HashMap<K, V, S>
+{
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        HashMap::get_mut(self, key)
+    }
+
+    #[inline]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+
+    #[inline]
+    fn insert_and_get_mut(&mut self, key: K, value: V) -> &mut V {
+        match self.entry(key) {
+            hash_map::Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                entry.into_mut()
+            }
+            hash_map::Entry::Vacant(entry) => entry.insert(value)
+        }
+    }
+
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: K, f: F) -> &mut V
+        where F: FnOnce() -> V
+    {
+        self.entry(key).or_insert_with(f)
+    }
+}
+
+impl<K: Ord, V> MapTarget for BTreeMap<K, V>
+{
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        BTreeMap::get_mut(self, key)
+    }
+
+    #[inline]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+
+    #[inline]
+    fn insert_and_get_mut(&mut self, key: K, value: V) -> &mut V {
+        match self.entry(key) {
+            btree_map::Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                entry.into_mut()
+            }
+            btree_map::Entry::Vacant(entry) => entry.insert(value)
+        }
+    }
+
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: K, f: F) -> &mut V
+        where F: FnOnce() -> V
+    {
+        self.entry(key).or_insert_with(f)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> MapTarget for indexmap::IndexMap<K, V, S>
+{
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        indexmap::IndexMap::get(self, key)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        indexmap::IndexMap::get_mut(self, key)
+    }
+
+    #[inline]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.shift_remove(key)
+    }
+
+    #[inline]
+    fn insert_and_get_mut(&mut self, key: K, value: V) -> &mut V {
+        match self.entry(key) {
+            indexmap::map::Entry::Occupied(mut entry) => {
+                entry.insert(value);
+                entry.into_mut()
+            }
+            indexmap::map::Entry::Vacant(entry) => entry.insert(value)
+        }
+    }
+
+    #[inline]
+    fn get_or_insert_with<F>(&mut self, key: K, f: F) -> &mut V
+        where F: FnOnce() -> V
+    {
+        self.entry(key).or_insert_with(f)
+    }
+}
+
+impl<L: UpgradableReadApi + ?Sized> MapUpgradableReadApiExt for L
+{
+    #[inline]
+    fn get_or_insert_with<'a, M, F>(&'a mut self, key: M::Key, f: F) -> GetOrInsertGuard<
+        <DowngradedGuard<'a, L> as MapReadGuard<'a>>::Mapped<M::Value>,
+        <<L::UpgradableReadGuard<'a> as UpgradableReadGuard>::UpgradeResult as MapWriteGuard<'a>>::Mapped<M::Value>
+    >
+        where
+            L: UpgradableReadApi<Target=M>,
+            M: MapTarget + 'a,
+            DowngradedGuard<'a, L>: MapReadGuard<'a, Target=M>,
+            <L::UpgradableReadGuard<'a> as UpgradableReadGuard>::UpgradeResult: MapWriteGuard<'a, Target=M>,
+            F: FnOnce() -> M::Value
+    {
+        let guard = self.upgradable_read();
+        if guard.get(&key).is_none() {
+            let guard = MapWriteGuard::map(guard.upgrade(), |map| map.get_or_insert_with(key, f));
+            return GetOrInsertGuard::Inserted(guard);
+        }
+        match MapReadGuard::try_map(guard.downgrade(), |map| map.get(&key)) {
+            Ok(guard) => GetOrInsertGuard::Found(guard),
+            Err(_) => unreachable!("downgrading keeps the lock held")
+        }
+    }
+}
+
+impl<L: WriteApi + ?Sized> MapWriteApiExt for L
+{
+    #[inline]
+    fn entry_locked<M>(&mut self, key: M::Key) -> EntryGuard<L::WriteGuard<'_>, M::Key>
+        where
+            L: WriteApi<Target=M>,
+            M: MapTarget
+    {
+        EntryGuard::new(self.write(), key)
+    }
+}
\ No newline at end of file
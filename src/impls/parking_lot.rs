@@ -1,29 +1,72 @@
 use {
     crate::{
+        poison::expect_healthy,
+        ClearPoisonApi,
         DowngradableWriteApi,
         DowngradableWriteGuard,
+        FairUnlockGuard,
         GuardedTarget,
+        IntoInnerApi,
+        LockIdentityApi,
+        LockStateApi,
+        MapReadGuard,
+        MapWriteGuard,
+        NewApi,
+        OwnedDowngradableWriteApi,
+        OwnedReadApi,
+        OwnedUpgradableReadApi,
+        OwnedWriteApi,
+        Poisonable,
+        PoisonApi,
+        PoisonGuard,
         ReadApi,
+        RecursiveReadApi,
+        SharedDowngradableWriteApi,
+        SharedUpgradableReadApi,
+        SharedWriteApi,
+        TimedReadApi,
+        TimedUpgradableReadApi,
+        TimedWriteApi,
+        TryLockError,
+        TryReadApi,
+        TryUpgradableReadApi,
+        TryWriteApi,
+        UnlockableGuard,
         UpgradableReadApi,
         UpgradableReadGuard,
         WriteApi,
     },
-    parking_lot::{RwLock, RwLockReadGuard, RwLockUpgradableReadGuard, RwLockWriteGuard},
+    parking_lot::{
+        ArcRwLockReadGuard,
+        ArcRwLockUpgradableReadGuard,
+        ArcRwLockWriteGuard,
+        MappedRwLockReadGuard,
+        MappedRwLockWriteGuard,
+        RawRwLock,
+        RwLock,
+        RwLockReadGuard,
+        RwLockUpgradableReadGuard,
+        RwLockWriteGuard,
+    },
+    std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    },
 };
 
-impl<T> GuardedTarget for RwLock<T> {
+impl<T: ?Sized> GuardedTarget for RwLock<T> {
     type Target = T;
 }
 
-impl<T> GuardedTarget for &RwLock<T> {
+impl<T: ?Sized> GuardedTarget for &RwLock<T> {
     type Target = T;
 }
 
-impl<T> GuardedTarget for &mut RwLock<T> {
+impl<T: ?Sized> GuardedTarget for &mut RwLock<T> {
     type Target = T;
 }
 
-impl<T> ReadApi for RwLock<T>
+impl<T: ?Sized> ReadApi for RwLock<T>
 {
     type ReadGuard<'a> = RwLockReadGuard<'a, T>
         where Self: 'a;
@@ -34,7 +77,7 @@ impl<T> ReadApi for RwLock<T>
     }
 }
 
-impl<T> WriteApi for RwLock<T>
+impl<T: ?Sized> WriteApi for RwLock<T>
 {
     type WriteGuard<'a> = &'a mut T
         where Self: 'a;
@@ -45,7 +88,7 @@ impl<T> WriteApi for RwLock<T>
     }
 }
 
-impl<T> UpgradableReadApi for RwLock<T>
+impl<T: ?Sized> UpgradableReadApi for RwLock<T>
 {
     type UpgradableReadGuard<'a> = &'a mut T
         where Self: 'a;
@@ -56,7 +99,7 @@ impl<T> UpgradableReadApi for RwLock<T>
     }
 }
 
-impl<T> DowngradableWriteApi for RwLock<T>
+impl<T: ?Sized> DowngradableWriteApi for RwLock<T>
 {
     type DowngradableWriteGuard<'a> = &'a mut T
         where Self: 'a;
@@ -67,7 +110,7 @@ impl<T> DowngradableWriteApi for RwLock<T>
     }
 }
 
-impl<T> ReadApi for &RwLock<T>
+impl<T: ?Sized> ReadApi for &RwLock<T>
 {
     type ReadGuard<'a> = RwLockReadGuard<'a, T>
         where Self: 'a;
@@ -78,7 +121,7 @@ impl<T> ReadApi for &RwLock<T>
     }
 }
 
-impl<T> WriteApi for &RwLock<T>
+impl<T: ?Sized> WriteApi for &RwLock<T>
 {
     type WriteGuard<'a> = RwLockWriteGuard<'a, T>
         where Self: 'a;
@@ -89,7 +132,7 @@ impl<T> WriteApi for &RwLock<T>
     }
 }
 
-impl<T> UpgradableReadApi for &RwLock<T>
+impl<T: ?Sized> UpgradableReadApi for &RwLock<T>
 {
     type UpgradableReadGuard<'a> = RwLockUpgradableReadGuard<'a, T>
         where Self: 'a;
@@ -100,7 +143,7 @@ impl<T> UpgradableReadApi for &RwLock<T>
     }
 }
 
-impl<T> DowngradableWriteApi for &RwLock<T>
+impl<T: ?Sized> DowngradableWriteApi for &RwLock<T>
 {
     type DowngradableWriteGuard<'a> = RwLockWriteGuard<'a, T>
         where Self: 'a;
@@ -111,7 +154,7 @@ impl<T> DowngradableWriteApi for &RwLock<T>
     }
 }
 
-impl<T> ReadApi for &mut RwLock<T>
+impl<T: ?Sized> ReadApi for &mut RwLock<T>
 {
     type ReadGuard<'a> = RwLockReadGuard<'a, T>
         where Self: 'a;
@@ -122,7 +165,7 @@ impl<T> ReadApi for &mut RwLock<T>
     }
 }
 
-impl<T> WriteApi for &mut RwLock<T>
+impl<T: ?Sized> WriteApi for &mut RwLock<T>
 {
     type WriteGuard<'a> = &'a mut T
         where Self: 'a;
@@ -133,7 +176,7 @@ impl<T> WriteApi for &mut RwLock<T>
     }
 }
 
-impl<T> UpgradableReadApi for &mut RwLock<T>
+impl<T: ?Sized> UpgradableReadApi for &mut RwLock<T>
 {
     type UpgradableReadGuard<'a> = RwLockUpgradableReadGuard<'a, T>
         where Self: 'a;
@@ -144,7 +187,7 @@ impl<T> UpgradableReadApi for &mut RwLock<T>
     }
 }
 
-impl<T> DowngradableWriteApi for &mut RwLock<T>
+impl<T: ?Sized> DowngradableWriteApi for &mut RwLock<T>
 {
     type DowngradableWriteGuard<'a> = &'a mut T
         where Self: 'a;
@@ -155,66 +198,1303 @@ impl<T> DowngradableWriteApi for &mut RwLock<T>
     }
 }
 
-impl<'a, T> UpgradableReadGuard for RwLockUpgradableReadGuard<'a, T>
+impl<T: ?Sized> TryReadApi for RwLock<T>
 {
-    type UpgradeResult = RwLockWriteGuard<'a, T>;
-    type UpgradeToDowngradableResult = RwLockWriteGuard<'a, T>;
+    #[inline]
+    fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read(self).ok_or(TryLockError::WouldBlock)
+    }
+}
 
+impl<T: ?Sized> TryWriteApi for RwLock<T>
+{
     #[inline]
-    fn upgrade(self) -> RwLockWriteGuard<'a, T> {
-        RwLockUpgradableReadGuard::upgrade(self)
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
     }
+}
 
+impl<T: ?Sized> TryUpgradableReadApi for RwLock<T>
+{
     #[inline]
-    fn upgrade_to_downgradable(self) -> RwLockWriteGuard<'a, T> {
-        RwLockUpgradableReadGuard::upgrade(self)
+    fn try_upgradable_read(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
     }
 }
 
-impl<'a, T> DowngradableWriteGuard for RwLockWriteGuard<'a, T>
+impl<T: ?Sized> TryReadApi for &RwLock<T>
 {
-    type DowngradeResult = RwLockReadGuard<'a, T>;
-    type DowngradeToUpgradableResult = RwLockUpgradableReadGuard<'a, T>;
+    #[inline]
+    fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read(self).ok_or(TryLockError::WouldBlock)
+    }
+}
 
+impl<T: ?Sized> TryWriteApi for &RwLock<T>
+{
     #[inline]
-    fn downgrade(self) -> RwLockReadGuard<'a, T> {
-        RwLockWriteGuard::downgrade(self)
+    fn try_write(&mut self) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        RwLock::try_write(self).ok_or(TryLockError::WouldBlock)
     }
+}
 
+impl<T: ?Sized> TryUpgradableReadApi for &RwLock<T>
+{
     #[inline]
-    fn downgrade_to_upgradable(self) -> RwLockUpgradableReadGuard<'a, T> {
-        RwLockWriteGuard::downgrade_to_upgradable(self)
+    fn try_upgradable_read(&mut self) -> Result<RwLockUpgradableReadGuard<'_, T>, TryLockError> {
+        RwLock::try_upgradable_read(self).ok_or(TryLockError::WouldBlock)
     }
 }
 
-impl<'a, T: ?Sized> UpgradableReadGuard for &'a mut T
+impl<T: ?Sized> TryReadApi for &mut RwLock<T>
 {
-    type UpgradeResult = Self;
-    type UpgradeToDowngradableResult = Self;
+    #[inline]
+    fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read(self).ok_or(TryLockError::WouldBlock)
+    }
+}
 
-    #[inline(always)]
-    fn upgrade(self) -> Self {
-        self
+impl<T: ?Sized> TryWriteApi for &mut RwLock<T>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
     }
+}
 
-    #[inline(always)]
-    fn upgrade_to_downgradable(self) -> Self {
-        self
+impl<T: ?Sized> TryUpgradableReadApi for &mut RwLock<T>
+{
+    #[inline]
+    fn try_upgradable_read(&mut self) -> Result<RwLockUpgradableReadGuard<'_, T>, TryLockError> {
+        RwLock::try_upgradable_read(self).ok_or(TryLockError::WouldBlock)
     }
 }
 
-impl<'a, T: ?Sized> DowngradableWriteGuard for &'a mut T
+impl<T: ?Sized> TimedReadApi for RwLock<T>
 {
-    type DowngradeResult = Self;
-    type DowngradeToUpgradableResult = Self;
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
 
-    #[inline(always)]
-    fn downgrade(self) -> Self {
-        self
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_until(self, deadline).ok_or(TryLockError::WouldBlock)
     }
+}
 
-    #[inline(always)]
-    fn downgrade_to_upgradable(self) -> Self {
-        self
+impl<T: ?Sized> TimedWriteApi for RwLock<T>
+{
+    #[inline]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+}
+
+impl<T: ?Sized> TimedUpgradableReadApi for RwLock<T>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for &RwLock<T>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> TimedWriteApi for &RwLock<T>
+{
+    #[inline]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        RwLock::try_write_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        RwLock::try_write_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> TimedUpgradableReadApi for &RwLock<T>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<RwLockUpgradableReadGuard<'_, T>, TryLockError>
+    {
+        RwLock::try_upgradable_read_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<RwLockUpgradableReadGuard<'_, T>, TryLockError>
+    {
+        RwLock::try_upgradable_read_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for &mut RwLock<T>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> TimedWriteApi for &mut RwLock<T>
+{
+    #[inline]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+}
+
+impl<T: ?Sized> TimedUpgradableReadApi for &mut RwLock<T>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<RwLockUpgradableReadGuard<'_, T>, TryLockError>
+    {
+        RwLock::try_upgradable_read_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<RwLockUpgradableReadGuard<'_, T>, TryLockError>
+    {
+        RwLock::try_upgradable_read_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for RwLock<T>
+{
+    #[inline]
+    fn read_recursive(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read_recursive(self)
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive(self).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive_for(self, timeout).ok_or(TryLockError::WouldBlock)
     }
-}
\ No newline at end of file
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for &RwLock<T>
+{
+    #[inline]
+    fn read_recursive(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read_recursive(self)
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive(self).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for &mut RwLock<T>
+{
+    #[inline]
+    fn read_recursive(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read_recursive(self)
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive(self).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive_for(self, timeout).ok_or(TryLockError::WouldBlock)
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        RwLock::try_read_recursive_until(self, deadline).ok_or(TryLockError::WouldBlock)
+    }
+}
+
+impl<T: ?Sized> OwnedReadApi for RwLock<T>
+{
+    type OwnedReadGuard = ArcRwLockReadGuard<RawRwLock, T>;
+
+    #[inline]
+    fn read_owned(self: &Arc<Self>) -> ArcRwLockReadGuard<RawRwLock, T> {
+        RwLock::read_arc(self)
+    }
+}
+
+impl<T: ?Sized> OwnedWriteApi for RwLock<T>
+{
+    type OwnedWriteGuard = ArcRwLockWriteGuard<RawRwLock, T>;
+
+    #[inline]
+    fn write_owned(self: &Arc<Self>) -> ArcRwLockWriteGuard<RawRwLock, T> {
+        RwLock::write_arc(self)
+    }
+}
+
+impl<T: ?Sized> OwnedUpgradableReadApi for RwLock<T>
+{
+    type OwnedUpgradableReadGuard = ArcRwLockUpgradableReadGuard<RawRwLock, T>;
+
+    #[inline]
+    fn upgradable_read_owned(self: &Arc<Self>) -> ArcRwLockUpgradableReadGuard<RawRwLock, T> {
+        RwLock::upgradable_read_arc(self)
+    }
+}
+
+impl<T: ?Sized> OwnedDowngradableWriteApi for RwLock<T>
+{
+    type OwnedDowngradableWriteGuard = ArcRwLockWriteGuard<RawRwLock, T>;
+
+    #[inline]
+    fn downgradable_write_owned(self: &Arc<Self>) -> ArcRwLockWriteGuard<RawRwLock, T> {
+        RwLock::write_arc(self)
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for RwLock<T>
+{
+    type SharedWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self)
+    }
+}
+
+impl<T: ?Sized> SharedUpgradableReadApi for RwLock<T>
+{
+    type SharedUpgradableReadGuard<'a> = RwLockUpgradableReadGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read_shared(&self) -> RwLockUpgradableReadGuard<'_, T> {
+        RwLock::upgradable_read(self)
+    }
+}
+
+impl<T: ?Sized> SharedDowngradableWriteApi for RwLock<T>
+{
+    type SharedDowngradableWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self)
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for &RwLock<T>
+{
+    type SharedWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self)
+    }
+}
+
+impl<T: ?Sized> SharedUpgradableReadApi for &RwLock<T>
+{
+    type SharedUpgradableReadGuard<'a> = RwLockUpgradableReadGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read_shared(&self) -> RwLockUpgradableReadGuard<'_, T> {
+        RwLock::upgradable_read(self)
+    }
+}
+
+impl<T: ?Sized> SharedDowngradableWriteApi for &RwLock<T>
+{
+    type SharedDowngradableWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self)
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for &mut RwLock<T>
+{
+    type SharedWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self)
+    }
+}
+
+impl<T: ?Sized> SharedUpgradableReadApi for &mut RwLock<T>
+{
+    type SharedUpgradableReadGuard<'a> = RwLockUpgradableReadGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read_shared(&self) -> RwLockUpgradableReadGuard<'_, T> {
+        RwLock::upgradable_read(self)
+    }
+}
+
+impl<T: ?Sized> SharedDowngradableWriteApi for &mut RwLock<T>
+{
+    type SharedDowngradableWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self)
+    }
+}
+
+impl<T> NewApi for RwLock<T>
+{
+    #[inline]
+    fn from_value(value: T) -> Self {
+        RwLock::new(value)
+    }
+}
+
+impl<T> IntoInnerApi for RwLock<T>
+{
+    #[inline]
+    fn into_inner(self) -> T {
+        RwLock::into_inner(self)
+    }
+}
+
+impl<T: ?Sized> PoisonApi for RwLock<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for RwLock<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &RwLock<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &RwLock<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &mut RwLock<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &mut RwLock<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> LockStateApi for RwLock<T>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        RwLock::is_locked(self)
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        RwLock::is_locked_exclusive(self)
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        if self.is_locked() && !self.is_locked_exclusive() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &RwLock<T>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        RwLock::is_locked(self)
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        RwLock::is_locked_exclusive(self)
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        if self.is_locked() && !self.is_locked_exclusive() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &mut RwLock<T>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        RwLock::is_locked(self)
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        RwLock::is_locked_exclusive(self)
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        if self.is_locked() && !self.is_locked_exclusive() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl<T> WriteApi for &Poisonable<RwLock<T>>
+{
+    type WriteGuard<'a> = PoisonGuard<'a, RwLockWriteGuard<'a, T>>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        let lock = *self;
+        let guard = RwLock::write(&lock.lock);
+        expect_healthy(lock.wrap(guard, true))
+    }
+}
+
+impl<T> TryWriteApi for &Poisonable<RwLock<T>>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        let lock = *self;
+        let guard = RwLock::try_write(&lock.lock).ok_or(TryLockError::WouldBlock)?;
+        lock.wrap(guard, true)
+    }
+}
+
+impl<T> TimedWriteApi for &Poisonable<RwLock<T>>
+{
+    #[inline]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        let lock = *self;
+        let guard = RwLock::try_write_for(&lock.lock, timeout).ok_or(TryLockError::WouldBlock)?;
+        lock.wrap(guard, true)
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        let lock = *self;
+        let guard = RwLock::try_write_until(&lock.lock, deadline).ok_or(TryLockError::WouldBlock)?;
+        lock.wrap(guard, true)
+    }
+}
+
+impl<T> UpgradableReadApi for &Poisonable<RwLock<T>>
+{
+    type UpgradableReadGuard<'a> = PoisonGuard<'a, RwLockUpgradableReadGuard<'a, T>>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        let lock = *self;
+        let guard = RwLock::upgradable_read(&lock.lock);
+        expect_healthy(lock.wrap(guard, false))
+    }
+}
+
+impl<T> TryUpgradableReadApi for &Poisonable<RwLock<T>>
+{
+    #[inline]
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError> {
+        let lock = *self;
+        let guard = RwLock::try_upgradable_read(&lock.lock).ok_or(TryLockError::WouldBlock)?;
+        lock.wrap(guard, false)
+    }
+}
+
+impl<T> TimedUpgradableReadApi for &Poisonable<RwLock<T>>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        let lock = *self;
+        let guard = RwLock::try_upgradable_read_for(&lock.lock, timeout)
+            .ok_or(TryLockError::WouldBlock)?;
+        lock.wrap(guard, false)
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        let lock = *self;
+        let guard = RwLock::try_upgradable_read_until(&lock.lock, deadline)
+            .ok_or(TryLockError::WouldBlock)?;
+        lock.wrap(guard, false)
+    }
+}
+
+impl<T> DowngradableWriteApi for &Poisonable<RwLock<T>>
+{
+    type DowngradableWriteGuard<'a> = PoisonGuard<'a, RwLockWriteGuard<'a, T>>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        let lock = *self;
+        let guard = RwLock::write(&lock.lock);
+        expect_healthy(lock.wrap(guard, true))
+    }
+}
+
+impl<'a, T: ?Sized> UpgradableReadGuard for RwLockUpgradableReadGuard<'a, T>
+{
+    type UpgradeResult = RwLockWriteGuard<'a, T>;
+    type UpgradeToDowngradableResult = RwLockWriteGuard<'a, T>;
+
+    #[inline]
+    fn upgrade(self) -> RwLockWriteGuard<'a, T> {
+        RwLockUpgradableReadGuard::upgrade(self)
+    }
+
+    #[inline]
+    fn try_upgrade(self) -> Result<RwLockWriteGuard<'a, T>, Self> {
+        RwLockUpgradableReadGuard::try_upgrade(self)
+    }
+
+    #[inline]
+    fn try_upgrade_for(self, timeout: Duration) -> Result<RwLockWriteGuard<'a, T>, Self> {
+        RwLockUpgradableReadGuard::try_upgrade_for(self, timeout)
+    }
+
+    #[inline]
+    fn try_upgrade_until(self, deadline: Instant) -> Result<RwLockWriteGuard<'a, T>, Self> {
+        RwLockUpgradableReadGuard::try_upgrade_until(self, deadline)
+    }
+
+    #[inline]
+    fn upgrade_to_downgradable(self) -> RwLockWriteGuard<'a, T> {
+        RwLockUpgradableReadGuard::upgrade(self)
+    }
+
+    #[inline]
+    fn downgrade(self) -> RwLockReadGuard<'a, T> {
+        RwLockUpgradableReadGuard::downgrade(self)
+    }
+
+    #[inline]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where F: FnOnce(&mut T) -> Ret
+    {
+        RwLockUpgradableReadGuard::with_upgraded(self, f)
+    }
+
+    #[inline]
+    fn try_with_upgraded<Ret, F>(&mut self, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        RwLockUpgradableReadGuard::try_with_upgraded(self, f)
+    }
+
+    #[inline]
+    fn try_with_upgraded_for<Ret, F>(&mut self, timeout: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        RwLockUpgradableReadGuard::try_with_upgraded_for(self, timeout, f)
+    }
+
+    #[inline]
+    fn try_with_upgraded_until<Ret, F>(&mut self, deadline: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        RwLockUpgradableReadGuard::try_with_upgraded_until(self, deadline, f)
+    }
+}
+
+impl<'a, T: ?Sized> DowngradableWriteGuard for RwLockWriteGuard<'a, T>
+{
+    type DowngradeResult = RwLockReadGuard<'a, T>;
+    type DowngradeToUpgradableResult = RwLockUpgradableReadGuard<'a, T>;
+
+    #[inline]
+    fn downgrade(self) -> RwLockReadGuard<'a, T> {
+        RwLockWriteGuard::downgrade(self)
+    }
+
+    #[inline]
+    fn downgrade_to_upgradable(self) -> RwLockUpgradableReadGuard<'a, T> {
+        RwLockWriteGuard::downgrade_to_upgradable(self)
+    }
+}
+
+impl<T: ?Sized> UpgradableReadGuard for ArcRwLockUpgradableReadGuard<RawRwLock, T>
+{
+    type UpgradeResult = ArcRwLockWriteGuard<RawRwLock, T>;
+    type UpgradeToDowngradableResult = ArcRwLockWriteGuard<RawRwLock, T>;
+
+    #[inline]
+    fn upgrade(self) -> ArcRwLockWriteGuard<RawRwLock, T> {
+        ArcRwLockUpgradableReadGuard::upgrade(self)
+    }
+
+    #[inline]
+    fn try_upgrade(self) -> Result<ArcRwLockWriteGuard<RawRwLock, T>, Self> {
+        ArcRwLockUpgradableReadGuard::try_upgrade(self)
+    }
+
+    #[inline]
+    fn try_upgrade_for(self, timeout: Duration) -> Result<ArcRwLockWriteGuard<RawRwLock, T>, Self> {
+        ArcRwLockUpgradableReadGuard::try_upgrade_for(self, timeout)
+    }
+
+    #[inline]
+    fn try_upgrade_until(self, deadline: Instant) -> Result<ArcRwLockWriteGuard<RawRwLock, T>, Self> {
+        ArcRwLockUpgradableReadGuard::try_upgrade_until(self, deadline)
+    }
+
+    #[inline]
+    fn upgrade_to_downgradable(self) -> ArcRwLockWriteGuard<RawRwLock, T> {
+        ArcRwLockUpgradableReadGuard::upgrade(self)
+    }
+
+    #[inline]
+    fn downgrade(self) -> ArcRwLockReadGuard<RawRwLock, T> {
+        ArcRwLockUpgradableReadGuard::downgrade(self)
+    }
+
+    #[inline]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where F: FnOnce(&mut T) -> Ret
+    {
+        ArcRwLockUpgradableReadGuard::with_upgraded(self, f)
+    }
+
+    #[inline]
+    fn try_with_upgraded<Ret, F>(&mut self, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        ArcRwLockUpgradableReadGuard::try_with_upgraded(self, f)
+    }
+
+    #[inline]
+    fn try_with_upgraded_for<Ret, F>(&mut self, timeout: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        ArcRwLockUpgradableReadGuard::try_with_upgraded_for(self, timeout, f)
+    }
+
+    #[inline]
+    fn try_with_upgraded_until<Ret, F>(&mut self, deadline: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        ArcRwLockUpgradableReadGuard::try_with_upgraded_until(self, deadline, f)
+    }
+}
+
+impl<T: ?Sized> DowngradableWriteGuard for ArcRwLockWriteGuard<RawRwLock, T>
+{
+    type DowngradeResult = ArcRwLockReadGuard<RawRwLock, T>;
+    type DowngradeToUpgradableResult = ArcRwLockUpgradableReadGuard<RawRwLock, T>;
+
+    #[inline]
+    fn downgrade(self) -> ArcRwLockReadGuard<RawRwLock, T> {
+        ArcRwLockWriteGuard::downgrade(self)
+    }
+
+    #[inline]
+    fn downgrade_to_upgradable(self) -> ArcRwLockUpgradableReadGuard<RawRwLock, T> {
+        ArcRwLockWriteGuard::downgrade_to_upgradable(self)
+    }
+}
+
+#[allow(clippy::needless_lifetimes)]
+impl<'a, T: ?Sized> UpgradableReadGuard for &'a mut T
+{
+    type UpgradeResult = Self;
+    type UpgradeToDowngradableResult = Self;
+
+    #[inline(always)]
+    fn upgrade(self) -> Self {
+        self
+    }
+
+    #[inline(always)]
+    fn try_upgrade(self) -> Result<Self, Self> {
+        Ok(self)
+    }
+
+    #[inline(always)]
+    fn try_upgrade_for(self, _: Duration) -> Result<Self, Self> {
+        Ok(self)
+    }
+
+    #[inline(always)]
+    fn try_upgrade_until(self, _: Instant) -> Result<Self, Self> {
+        Ok(self)
+    }
+
+    #[inline(always)]
+    fn upgrade_to_downgradable(self) -> Self {
+        self
+    }
+
+    #[inline(always)]
+    fn downgrade(self) -> Self {
+        self
+    }
+
+    #[inline(always)]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where F: FnOnce(&mut T) -> Ret
+    {
+        f(self)
+    }
+
+    #[inline(always)]
+    fn try_with_upgraded<Ret, F>(&mut self, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        Some(f(self))
+    }
+
+    #[inline(always)]
+    fn try_with_upgraded_for<Ret, F>(&mut self, _: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        Some(f(self))
+    }
+
+    #[inline(always)]
+    fn try_with_upgraded_until<Ret, F>(&mut self, _: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut T) -> Ret
+    {
+        Some(f(self))
+    }
+}
+
+#[allow(clippy::needless_lifetimes)]
+impl<'a, T: ?Sized> DowngradableWriteGuard for &'a mut T
+{
+    type DowngradeResult = Self;
+    type DowngradeToUpgradableResult = Self;
+
+    #[inline(always)]
+    fn downgrade(self) -> Self {
+        self
+    }
+
+    #[inline(always)]
+    fn downgrade_to_upgradable(self) -> Self {
+        self
+    }
+}
+
+impl<'a, T: ?Sized> MapReadGuard<'a> for RwLockReadGuard<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = MappedRwLockReadGuard<'a, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> MappedRwLockReadGuard<'a, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> &U
+    {
+        RwLockReadGuard::map(s, f)
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<MappedRwLockReadGuard<'a, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> Option<&U>
+    {
+        RwLockReadGuard::try_map(s, f)
+    }
+}
+
+impl<'a, T: ?Sized> MapReadGuard<'a> for MappedRwLockReadGuard<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = MappedRwLockReadGuard<'a, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> MappedRwLockReadGuard<'a, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> &U
+    {
+        MappedRwLockReadGuard::map(s, f)
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<MappedRwLockReadGuard<'a, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> Option<&U>
+    {
+        MappedRwLockReadGuard::try_map(s, f)
+    }
+}
+
+impl<'a, T: ?Sized> MapWriteGuard<'a> for RwLockWriteGuard<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = MappedRwLockWriteGuard<'a, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> MappedRwLockWriteGuard<'a, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> &mut U
+    {
+        RwLockWriteGuard::map(s, f)
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<MappedRwLockWriteGuard<'a, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        RwLockWriteGuard::try_map(s, f)
+    }
+}
+
+impl<'a, T: ?Sized> MapWriteGuard<'a> for MappedRwLockWriteGuard<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = MappedRwLockWriteGuard<'a, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> MappedRwLockWriteGuard<'a, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> &mut U
+    {
+        MappedRwLockWriteGuard::map(s, f)
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<MappedRwLockWriteGuard<'a, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        MappedRwLockWriteGuard::try_map(s, f)
+    }
+}
+
+impl<'a, T: ?Sized> MapReadGuard<'a> for &'a T
+{
+    type Mapped<U: ?Sized + 'a> = &'a U;
+
+    #[inline(always)]
+    fn map<U, F>(s: Self, f: F) -> &'a U
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> &U
+    {
+        f(s)
+    }
+
+    #[inline(always)]
+    fn try_map<U, F>(s: Self, f: F) -> Result<&'a U, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> Option<&U>
+    {
+        f(s).ok_or(s)
+    }
+}
+
+impl<'a, T: ?Sized> MapReadGuard<'a> for &'a mut T
+{
+    type Mapped<U: ?Sized + 'a> = &'a U;
+
+    #[inline(always)]
+    fn map<U, F>(s: Self, f: F) -> &'a U
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> &U
+    {
+        f(s)
+    }
+
+    #[inline(always)]
+    fn try_map<U, F>(s: Self, f: F) -> Result<&'a U, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> Option<&U>
+    {
+        // Reborrowing through a raw pointer works around the borrow checker
+        // rejecting the return of `s` after a conditional borrow of it.
+        let ptr: *const T = s;
+        // SAFETY: `ptr` comes from `s`, which is not used until the borrow ends.
+        match f(unsafe { &*ptr }) {
+            Some(mapped) => Ok(mapped),
+            None => Err(s)
+        }
+    }
+}
+
+impl<'a, T: ?Sized> MapWriteGuard<'a> for &'a mut T
+{
+    type Mapped<U: ?Sized + 'a> = &'a mut U;
+
+    #[inline(always)]
+    fn map<U, F>(s: Self, f: F) -> &'a mut U
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> &mut U
+    {
+        f(s)
+    }
+
+    #[inline(always)]
+    fn try_map<U, F>(s: Self, f: F) -> Result<&'a mut U, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        // Reborrowing through a raw pointer works around the borrow checker
+        // rejecting the return of `s` after a conditional borrow of it.
+        let ptr: *mut T = s;
+        // SAFETY: `ptr` comes from `s`, which is not used until the borrow ends.
+        match f(unsafe { &mut *ptr }) {
+            Some(mapped) => Ok(mapped),
+            None => Err(s)
+        }
+    }
+}
+
+impl<'a, T: ?Sized> UnlockableGuard for RwLockReadGuard<'a, T>
+{
+    #[inline]
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        RwLockReadGuard::unlocked(s, f)
+    }
+
+    #[inline]
+    fn bump(s: &mut Self) {
+        RwLockReadGuard::bump(s)
+    }
+}
+
+impl<'a, T: ?Sized> UnlockableGuard for RwLockWriteGuard<'a, T>
+{
+    #[inline]
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        RwLockWriteGuard::unlocked(s, f)
+    }
+
+    #[inline]
+    fn bump(s: &mut Self) {
+        RwLockWriteGuard::bump(s)
+    }
+}
+
+impl<'a, T: ?Sized> UnlockableGuard for RwLockUpgradableReadGuard<'a, T>
+{
+    #[inline]
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        RwLockUpgradableReadGuard::unlocked(s, f)
+    }
+
+    #[inline]
+    fn bump(s: &mut Self) {
+        RwLockUpgradableReadGuard::bump(s)
+    }
+}
+
+impl<T: ?Sized> UnlockableGuard for ArcRwLockReadGuard<RawRwLock, T>
+{
+    #[inline]
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        ArcRwLockReadGuard::unlocked(s, f)
+    }
+
+    #[inline]
+    fn bump(s: &mut Self) {
+        ArcRwLockReadGuard::bump(s)
+    }
+}
+
+impl<T: ?Sized> UnlockableGuard for ArcRwLockWriteGuard<RawRwLock, T>
+{
+    #[inline]
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        ArcRwLockWriteGuard::unlocked(s, f)
+    }
+
+    #[inline]
+    fn bump(s: &mut Self) {
+        ArcRwLockWriteGuard::bump(s)
+    }
+}
+
+impl<T: ?Sized> UnlockableGuard for ArcRwLockUpgradableReadGuard<RawRwLock, T>
+{
+    #[inline]
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        ArcRwLockUpgradableReadGuard::unlocked(s, f)
+    }
+
+    #[inline]
+    fn bump(s: &mut Self) {
+        ArcRwLockUpgradableReadGuard::bump(s)
+    }
+}
+
+impl<T: ?Sized> UnlockableGuard for &T
+{
+    #[inline(always)]
+    fn unlocked<U, F>(_: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        f()
+    }
+
+    #[inline(always)]
+    fn bump(_: &mut Self) {}
+}
+
+impl<T: ?Sized> UnlockableGuard for &mut T
+{
+    #[inline(always)]
+    fn unlocked<U, F>(_: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        f()
+    }
+
+    #[inline(always)]
+    fn bump(_: &mut Self) {}
+}
+
+impl<'a, T: ?Sized> FairUnlockGuard for RwLockReadGuard<'a, T>
+{
+    #[inline]
+    fn unlock_fair(s: Self) {
+        RwLockReadGuard::unlock_fair(s)
+    }
+
+    #[inline]
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        RwLockReadGuard::unlocked_fair(s, f)
+    }
+}
+
+impl<'a, T: ?Sized> FairUnlockGuard for RwLockWriteGuard<'a, T>
+{
+    #[inline]
+    fn unlock_fair(s: Self) {
+        RwLockWriteGuard::unlock_fair(s)
+    }
+
+    #[inline]
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        RwLockWriteGuard::unlocked_fair(s, f)
+    }
+}
+
+impl<'a, T: ?Sized> FairUnlockGuard for RwLockUpgradableReadGuard<'a, T>
+{
+    #[inline]
+    fn unlock_fair(s: Self) {
+        RwLockUpgradableReadGuard::unlock_fair(s)
+    }
+
+    #[inline]
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        RwLockUpgradableReadGuard::unlocked_fair(s, f)
+    }
+}
+
+impl<T: ?Sized> FairUnlockGuard for ArcRwLockReadGuard<RawRwLock, T>
+{
+    #[inline]
+    fn unlock_fair(s: Self) {
+        ArcRwLockReadGuard::unlock_fair(s)
+    }
+
+    #[inline]
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        ArcRwLockReadGuard::unlocked_fair(s, f)
+    }
+}
+
+impl<T: ?Sized> FairUnlockGuard for ArcRwLockWriteGuard<RawRwLock, T>
+{
+    #[inline]
+    fn unlock_fair(s: Self) {
+        ArcRwLockWriteGuard::unlock_fair(s)
+    }
+
+    #[inline]
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        ArcRwLockWriteGuard::unlocked_fair(s, f)
+    }
+}
+
+impl<T: ?Sized> FairUnlockGuard for ArcRwLockUpgradableReadGuard<RawRwLock, T>
+{
+    #[inline]
+    fn unlock_fair(s: Self) {
+        ArcRwLockUpgradableReadGuard::unlock_fair(s)
+    }
+
+    #[inline]
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        ArcRwLockUpgradableReadGuard::unlocked_fair(s, f)
+    }
+}
+
+impl<T: ?Sized> FairUnlockGuard for &T
+{
+    #[inline(always)]
+    fn unlock_fair(_: Self) {}
+
+    #[inline(always)]
+    fn unlocked_fair<U, F>(_: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        f()
+    }
+}
+
+impl<T: ?Sized> FairUnlockGuard for &mut T
+{
+    #[inline(always)]
+    fn unlock_fair(_: Self) {}
+
+    #[inline(always)]
+    fn unlocked_fair<U, F>(_: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        f()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for RwLock<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self as *const Self as *const ()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &RwLock<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &mut RwLock<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
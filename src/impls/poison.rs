@@ -0,0 +1,694 @@
+use {
+    crate::{
+        poison::{backend_poisoning, expect_healthy, wrap_guard},
+        DowngradableWriteApi,
+        DowngradableWriteGuard,
+        FairUnlockGuard,
+        GuardedTarget,
+        IntoInnerApi,
+        LockIdentityApi,
+        LockStateApi,
+        MapReadGuard,
+        MapWriteGuard,
+        NewApi,
+        PoisonApi,
+        PoisonGuard,
+        PoisonPolicy,
+        Poisonable,
+        ReadApi,
+        RecursiveReadApi,
+        TimedReadApi,
+        TimedUpgradableReadApi,
+        TimedWriteApi,
+        TryLockError,
+        TryReadApi,
+        TryUpgradableReadApi,
+        TryWriteApi,
+        UnlockableGuard,
+        UpgradableReadApi,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::{
+        ops::{Deref, DerefMut},
+        time::{Duration, Instant},
+    },
+};
+
+impl<L: GuardedTarget> GuardedTarget for Poisonable<L> {
+    type Target = L::Target;
+}
+
+impl<L: GuardedTarget> GuardedTarget for &Poisonable<L> {
+    type Target = L::Target;
+}
+
+impl<L: GuardedTarget> GuardedTarget for &mut Poisonable<L> {
+    type Target = L::Target;
+}
+
+impl<L: NewApi> NewApi for Poisonable<L>
+    where L::Target: Sized
+{
+    #[inline]
+    fn from_value(value: L::Target) -> Self {
+        Self::new(L::from_value(value), PoisonPolicy::default())
+    }
+}
+
+impl<L: IntoInnerApi> IntoInnerApi for Poisonable<L>
+    where L::Target: Sized
+{
+    #[inline]
+    fn into_inner(self) -> L::Target {
+        self.lock.into_inner()
+    }
+}
+
+impl<L: ReadApi + PoisonApi> ReadApi for Poisonable<L>
+{
+    type ReadGuard<'a> = PoisonGuard<'a, L::ReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Self::ReadGuard<'_> {
+        expect_healthy(self.wrap(self.lock.read(), false))
+    }
+}
+
+impl<L: TryReadApi + PoisonApi> TryReadApi for Poisonable<L>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        let result = self.lock.try_read();
+        self.wrap(backend_poisoning(self.is_poisoned(), result)?, false)
+    }
+}
+
+impl<L: TimedReadApi + PoisonApi> TimedReadApi for Poisonable<L>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        let result = self.lock.try_read_for(timeout);
+        self.wrap(backend_poisoning(self.is_poisoned(), result)?, false)
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        let result = self.lock.try_read_until(deadline);
+        self.wrap(backend_poisoning(self.is_poisoned(), result)?, false)
+    }
+}
+
+impl<L: RecursiveReadApi + PoisonApi> RecursiveReadApi for Poisonable<L>
+{
+    #[inline]
+    fn read_recursive(&self) -> Self::ReadGuard<'_> {
+        expect_healthy(self.wrap(self.lock.read_recursive(), false))
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        let result = self.lock.try_read_recursive();
+        self.wrap(backend_poisoning(self.is_poisoned(), result)?, false)
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        let result = self.lock.try_read_recursive_for(timeout);
+        self.wrap(backend_poisoning(self.is_poisoned(), result)?, false)
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        let result = self.lock.try_read_recursive_until(deadline);
+        self.wrap(backend_poisoning(self.is_poisoned(), result)?, false)
+    }
+}
+
+impl<L: WriteApi + PoisonApi> WriteApi for Poisonable<L>
+{
+    type WriteGuard<'a> = PoisonGuard<'a, L::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        let poisoned = self.is_poisoned();
+        let guard = self.lock.write();
+        expect_healthy(wrap_guard(&self.poisoned, self.policy, poisoned, guard, true))
+    }
+}
+
+impl<L: TryWriteApi + PoisonApi> TryWriteApi for Poisonable<L>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        if self.is_poisoned() {
+            return Err(TryLockError::Poisoned);
+        }
+        let guard = backend_poisoning(false, self.lock.try_write())?;
+        wrap_guard(&self.poisoned, self.policy, false, guard, true)
+    }
+}
+
+impl<L: TimedWriteApi + PoisonApi> TimedWriteApi for Poisonable<L>
+{
+    #[inline]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        if self.is_poisoned() {
+            return Err(TryLockError::Poisoned);
+        }
+        let guard = backend_poisoning(false, self.lock.try_write_for(timeout))?;
+        wrap_guard(&self.poisoned, self.policy, false, guard, true)
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        if self.is_poisoned() {
+            return Err(TryLockError::Poisoned);
+        }
+        let guard = backend_poisoning(false, self.lock.try_write_until(deadline))?;
+        wrap_guard(&self.poisoned, self.policy, false, guard, true)
+    }
+}
+
+impl<L: UpgradableReadApi + PoisonApi> UpgradableReadApi for Poisonable<L>
+{
+    type UpgradableReadGuard<'a> = PoisonGuard<'a, L::UpgradableReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        let poisoned = self.is_poisoned();
+        let guard = self.lock.upgradable_read();
+        expect_healthy(wrap_guard(&self.poisoned, self.policy, poisoned, guard, false))
+    }
+}
+
+impl<L: TryUpgradableReadApi + PoisonApi> TryUpgradableReadApi for Poisonable<L>
+{
+    #[inline]
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError> {
+        if self.is_poisoned() {
+            return Err(TryLockError::Poisoned);
+        }
+        let guard = backend_poisoning(false, self.lock.try_upgradable_read())?;
+        wrap_guard(&self.poisoned, self.policy, false, guard, false)
+    }
+}
+
+impl<L: TimedUpgradableReadApi + PoisonApi> TimedUpgradableReadApi for Poisonable<L>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        if self.is_poisoned() {
+            return Err(TryLockError::Poisoned);
+        }
+        let guard = backend_poisoning(false, self.lock.try_upgradable_read_for(timeout))?;
+        wrap_guard(&self.poisoned, self.policy, false, guard, false)
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        if self.is_poisoned() {
+            return Err(TryLockError::Poisoned);
+        }
+        let guard = backend_poisoning(false, self.lock.try_upgradable_read_until(deadline))?;
+        wrap_guard(&self.poisoned, self.policy, false, guard, false)
+    }
+}
+
+impl<L: DowngradableWriteApi + PoisonApi> DowngradableWriteApi for Poisonable<L>
+{
+    type DowngradableWriteGuard<'a> = PoisonGuard<'a, L::DowngradableWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        let poisoned = self.is_poisoned();
+        let guard = self.lock.downgradable_write();
+        expect_healthy(wrap_guard(&self.poisoned, self.policy, poisoned, guard, true))
+    }
+}
+
+impl<L: ReadApi + PoisonApi> ReadApi for &Poisonable<L>
+{
+    type ReadGuard<'a> = PoisonGuard<'a, L::ReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Self::ReadGuard<'_> {
+        (**self).read()
+    }
+}
+
+impl<L: TryReadApi + PoisonApi> TryReadApi for &Poisonable<L>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read()
+    }
+}
+
+impl<L: TimedReadApi + PoisonApi> TimedReadApi for &Poisonable<L>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_until(deadline)
+    }
+}
+
+impl<L: RecursiveReadApi + PoisonApi> RecursiveReadApi for &Poisonable<L>
+{
+    #[inline]
+    fn read_recursive(&self) -> Self::ReadGuard<'_> {
+        (**self).read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_until(deadline)
+    }
+}
+
+impl<L: ReadApi + PoisonApi> ReadApi for &mut Poisonable<L>
+{
+    type ReadGuard<'a> = PoisonGuard<'a, L::ReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Self::ReadGuard<'_> {
+        (**self).read()
+    }
+}
+
+impl<L: TryReadApi + PoisonApi> TryReadApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read()
+    }
+}
+
+impl<L: TimedReadApi + PoisonApi> TimedReadApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn try_read_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_until(deadline)
+    }
+}
+
+impl<L: RecursiveReadApi + PoisonApi> RecursiveReadApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn read_recursive(&self) -> Self::ReadGuard<'_> {
+        (**self).read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive(&self) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive()
+    }
+
+    #[inline]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_for(timeout)
+    }
+
+    #[inline]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError> {
+        (**self).try_read_recursive_until(deadline)
+    }
+}
+
+impl<L: WriteApi + PoisonApi> WriteApi for &mut Poisonable<L>
+{
+    type WriteGuard<'a> = PoisonGuard<'a, L::WriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        (**self).write()
+    }
+}
+
+impl<L: TryWriteApi + PoisonApi> TryWriteApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        (**self).try_write()
+    }
+}
+
+impl<L: TimedWriteApi + PoisonApi> TimedWriteApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn try_write_for(&mut self, timeout: Duration) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        (**self).try_write_for(timeout)
+    }
+
+    #[inline]
+    fn try_write_until(&mut self, deadline: Instant) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        (**self).try_write_until(deadline)
+    }
+}
+
+impl<L: UpgradableReadApi + PoisonApi> UpgradableReadApi for &mut Poisonable<L>
+{
+    type UpgradableReadGuard<'a> = PoisonGuard<'a, L::UpgradableReadGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> Self::UpgradableReadGuard<'_> {
+        (**self).upgradable_read()
+    }
+}
+
+impl<L: TryUpgradableReadApi + PoisonApi> TryUpgradableReadApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError> {
+        (**self).try_upgradable_read()
+    }
+}
+
+impl<L: TimedUpgradableReadApi + PoisonApi> TimedUpgradableReadApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        (**self).try_upgradable_read_for(timeout)
+    }
+
+    #[inline]
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>
+    {
+        (**self).try_upgradable_read_until(deadline)
+    }
+}
+
+impl<L: DowngradableWriteApi + PoisonApi> DowngradableWriteApi for &mut Poisonable<L>
+{
+    type DowngradableWriteGuard<'a> = PoisonGuard<'a, L::DowngradableWriteGuard<'a>>
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_> {
+        (**self).downgradable_write()
+    }
+}
+
+impl<L: LockStateApi> LockStateApi for Poisonable<L>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        self.get_ref().is_locked()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        self.get_ref().is_locked_exclusive()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        self.get_ref().reader_count()
+    }
+}
+
+impl<L: LockStateApi> LockStateApi for &Poisonable<L>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        (**self).is_locked()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        (**self).is_locked_exclusive()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        (**self).reader_count()
+    }
+}
+
+impl<L: LockStateApi> LockStateApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        (**self).is_locked()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        (**self).is_locked_exclusive()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        (**self).reader_count()
+    }
+}
+
+impl<G: Deref> Deref for PoisonGuard<'_, G>
+{
+    type Target = G::Target;
+
+    #[inline(always)]
+    fn deref(&self) -> &G::Target {
+        &self.guard
+    }
+}
+
+impl<G: DerefMut> DerefMut for PoisonGuard<'_, G>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut G::Target {
+        &mut self.guard
+    }
+}
+
+impl<'a, G: UpgradableReadGuard> UpgradableReadGuard for PoisonGuard<'a, G>
+{
+    type UpgradeResult = PoisonGuard<'a, G::UpgradeResult>;
+    type UpgradeToDowngradableResult = PoisonGuard<'a, G::UpgradeToDowngradableResult>;
+
+    #[inline]
+    fn upgrade(self) -> Self::UpgradeResult {
+        PoisonGuard { flag: self.flag.arm(), guard: self.guard.upgrade() }
+    }
+
+    #[inline]
+    fn try_upgrade(self) -> Result<Self::UpgradeResult, Self> {
+        match self.guard.try_upgrade() {
+            Ok(guard) => Ok(PoisonGuard { flag: self.flag.arm(), guard }),
+            Err(guard) => Err(PoisonGuard { flag: self.flag, guard })
+        }
+    }
+
+    #[inline]
+    fn try_upgrade_for(self, timeout: Duration) -> Result<Self::UpgradeResult, Self> {
+        match self.guard.try_upgrade_for(timeout) {
+            Ok(guard) => Ok(PoisonGuard { flag: self.flag.arm(), guard }),
+            Err(guard) => Err(PoisonGuard { flag: self.flag, guard })
+        }
+    }
+
+    #[inline]
+    fn try_upgrade_until(self, deadline: Instant) -> Result<Self::UpgradeResult, Self> {
+        match self.guard.try_upgrade_until(deadline) {
+            Ok(guard) => Ok(PoisonGuard { flag: self.flag.arm(), guard }),
+            Err(guard) => Err(PoisonGuard { flag: self.flag, guard })
+        }
+    }
+
+    #[inline]
+    fn upgrade_to_downgradable(self) -> Self::UpgradeToDowngradableResult {
+        PoisonGuard { flag: self.flag.arm(), guard: self.guard.upgrade_to_downgradable() }
+    }
+
+    #[inline]
+    fn downgrade(self) -> <Self::UpgradeToDowngradableResult as DowngradableWriteGuard>::DowngradeResult {
+        PoisonGuard { flag: self.flag, guard: self.guard.downgrade() }
+    }
+
+    #[inline]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        self.flag.armed_while(|| self.guard.with_upgraded(f))
+    }
+
+    #[inline]
+    fn try_with_upgraded<Ret, F>(&mut self, f: F) -> Option<Ret>
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        self.flag.armed_while(|| self.guard.try_with_upgraded(f))
+    }
+
+    #[inline]
+    fn try_with_upgraded_for<Ret, F>(&mut self, timeout: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        self.flag.armed_while(|| self.guard.try_with_upgraded_for(timeout, f))
+    }
+
+    #[inline]
+    fn try_with_upgraded_until<Ret, F>(&mut self, deadline: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut G::Target) -> Ret
+    {
+        self.flag.armed_while(|| self.guard.try_with_upgraded_until(deadline, f))
+    }
+}
+
+impl<'a, G: DowngradableWriteGuard> DowngradableWriteGuard for PoisonGuard<'a, G>
+{
+    type DowngradeResult = PoisonGuard<'a, G::DowngradeResult>;
+    type DowngradeToUpgradableResult = PoisonGuard<'a, G::DowngradeToUpgradableResult>;
+
+    #[inline]
+    fn downgrade(self) -> Self::DowngradeResult {
+        PoisonGuard { flag: self.flag.disarm(), guard: self.guard.downgrade() }
+    }
+
+    #[inline]
+    fn downgrade_to_upgradable(self) -> Self::DowngradeToUpgradableResult {
+        PoisonGuard { flag: self.flag.disarm(), guard: self.guard.downgrade_to_upgradable() }
+    }
+}
+
+impl<'a, G: MapReadGuard<'a>> MapReadGuard<'a> for PoisonGuard<'a, G>
+{
+    type Mapped<U: ?Sized + 'a> = PoisonGuard<'a, G::Mapped<U>>;
+
+    #[inline(always)]
+    fn map<U, F>(s: Self, f: F) -> PoisonGuard<'a, G::Mapped<U>>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&G::Target) -> &U
+    {
+        PoisonGuard { flag: s.flag, guard: G::map(s.guard, f) }
+    }
+
+    #[inline(always)]
+    fn try_map<U, F>(s: Self, f: F) -> Result<PoisonGuard<'a, G::Mapped<U>>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&G::Target) -> Option<&U>
+    {
+        match G::try_map(s.guard, f) {
+            Ok(guard) => Ok(PoisonGuard { flag: s.flag, guard }),
+            Err(guard) => Err(PoisonGuard { flag: s.flag, guard })
+        }
+    }
+}
+
+impl<'a, G: MapWriteGuard<'a>> MapWriteGuard<'a> for PoisonGuard<'a, G>
+{
+    type Mapped<U: ?Sized + 'a> = PoisonGuard<'a, G::Mapped<U>>;
+
+    #[inline(always)]
+    fn map<U, F>(s: Self, f: F) -> PoisonGuard<'a, G::Mapped<U>>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut G::Target) -> &mut U
+    {
+        PoisonGuard { flag: s.flag, guard: G::map(s.guard, f) }
+    }
+
+    #[inline(always)]
+    fn try_map<U, F>(s: Self, f: F) -> Result<PoisonGuard<'a, G::Mapped<U>>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut G::Target) -> Option<&mut U>
+    {
+        match G::try_map(s.guard, f) {
+            Ok(guard) => Ok(PoisonGuard { flag: s.flag, guard }),
+            Err(guard) => Err(PoisonGuard { flag: s.flag, guard })
+        }
+    }
+}
+
+impl<G: UnlockableGuard> UnlockableGuard for PoisonGuard<'_, G>
+{
+    #[inline]
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        s.flag.disarmed_while(|| G::unlocked(&mut s.guard, f))
+    }
+
+    #[inline(always)]
+    fn bump(s: &mut Self) {
+        G::bump(&mut s.guard)
+    }
+}
+
+impl<G: FairUnlockGuard> FairUnlockGuard for PoisonGuard<'_, G>
+{
+    #[inline]
+    fn unlock_fair(s: Self) {
+        let PoisonGuard { flag, guard } = s;
+        drop(flag);
+        G::unlock_fair(guard)
+    }
+
+    #[inline]
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U
+    {
+        s.flag.disarmed_while(|| G::unlocked_fair(&mut s.guard, f))
+    }
+}
+
+impl<L: LockIdentityApi> LockIdentityApi for Poisonable<L>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self.get_ref().lock_addr()
+    }
+}
+
+impl<L: LockIdentityApi> LockIdentityApi for &Poisonable<L>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<L: LockIdentityApi> LockIdentityApi for &mut Poisonable<L>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
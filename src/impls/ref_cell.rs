@@ -0,0 +1,419 @@
+use {
+    crate::{
+        ClearPoisonApi,
+        DowngradableWriteApi,
+        GuardedTarget,
+        IntoInnerApi,
+        LockIdentityApi,
+        LockStateApi,
+        MapReadGuard,
+        MapWriteGuard,
+        NewApi,
+        PoisonApi,
+        ReadApi,
+        SharedWriteApi,
+        TryLockError,
+        TryReadApi,
+        TryWriteApi,
+        UpgradableReadApi,
+        WriteApi,
+    },
+    std::cell::{Ref, RefCell, RefMut},
+};
+
+impl<T: ?Sized> GuardedTarget for RefCell<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> GuardedTarget for &RefCell<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> GuardedTarget for &mut RefCell<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> ReadApi for RefCell<T>
+{
+    type ReadGuard<'a> = Ref<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Ref<'_, T> {
+        self.borrow()
+    }
+}
+
+impl<T: ?Sized> WriteApi for RefCell<T>
+{
+    type WriteGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T: ?Sized> UpgradableReadApi for RefCell<T>
+{
+    type UpgradableReadGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T: ?Sized> DowngradableWriteApi for RefCell<T>
+{
+    type DowngradableWriteGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T: ?Sized> ReadApi for &RefCell<T>
+{
+    type ReadGuard<'a> = Ref<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Ref<'_, T> {
+        self.borrow()
+    }
+}
+
+impl<T: ?Sized> WriteApi for &RefCell<T>
+{
+    type WriteGuard<'a> = RefMut<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> RefMut<'_, T> {
+        self.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> ReadApi for &mut RefCell<T>
+{
+    type ReadGuard<'a> = Ref<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> Ref<'_, T> {
+        self.borrow()
+    }
+}
+
+impl<T: ?Sized> WriteApi for &mut RefCell<T>
+{
+    type WriteGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T: ?Sized> UpgradableReadApi for &mut RefCell<T>
+{
+    type UpgradableReadGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn upgradable_read(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T: ?Sized> DowngradableWriteApi for &mut RefCell<T>
+{
+    type DowngradableWriteGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn downgradable_write(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T: ?Sized> TryReadApi for RefCell<T>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Ref<'_, T>, TryLockError> {
+        Ok(self.try_borrow()?)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for RefCell<T>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &RefCell<T>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Ref<'_, T>, TryLockError> {
+        Ok(self.try_borrow()?)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for &RefCell<T>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<RefMut<'_, T>, TryLockError> {
+        Ok(self.try_borrow_mut()?)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &mut RefCell<T>
+{
+    #[inline]
+    fn try_read(&self) -> Result<Ref<'_, T>, TryLockError> {
+        Ok(self.try_borrow()?)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for &mut RefCell<T>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut())
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for RefCell<T>
+{
+    type SharedWriteGuard<'a> = RefMut<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RefMut<'_, T> {
+        self.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for &RefCell<T>
+{
+    type SharedWriteGuard<'a> = RefMut<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RefMut<'_, T> {
+        self.borrow_mut()
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for &mut RefCell<T>
+{
+    type SharedWriteGuard<'a> = RefMut<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RefMut<'_, T> {
+        self.borrow_mut()
+    }
+}
+
+impl<T> NewApi for RefCell<T>
+{
+    #[inline]
+    fn from_value(value: T) -> Self {
+        RefCell::new(value)
+    }
+}
+
+impl<T> IntoInnerApi for RefCell<T>
+{
+    #[inline]
+    fn into_inner(self) -> T {
+        RefCell::into_inner(self)
+    }
+}
+
+impl<T: ?Sized> PoisonApi for RefCell<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for RefCell<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &RefCell<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &RefCell<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &mut RefCell<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &mut RefCell<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> LockStateApi for RefCell<T>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        self.try_borrow_mut().is_err()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        self.try_borrow().is_err()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        if self.is_locked() && !self.is_locked_exclusive() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &RefCell<T>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        self.try_borrow_mut().is_err()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        self.try_borrow().is_err()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        if self.is_locked() && !self.is_locked_exclusive() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &mut RefCell<T>
+{
+    #[inline]
+    fn is_locked(&self) -> bool {
+        self.try_borrow_mut().is_err()
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        self.try_borrow().is_err()
+    }
+
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        if self.is_locked() && !self.is_locked_exclusive() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl<'a, T: ?Sized> MapReadGuard<'a> for Ref<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = Ref<'a, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> Ref<'a, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> &U
+    {
+        Ref::map(s, f)
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<Ref<'a, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> Option<&U>
+    {
+        Ref::filter_map(s, f)
+    }
+}
+
+impl<'a, T: ?Sized> MapWriteGuard<'a> for RefMut<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = RefMut<'a, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> RefMut<'a, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> &mut U
+    {
+        RefMut::map(s, f)
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<RefMut<'a, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        RefMut::filter_map(s, f)
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for RefCell<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self as *const Self as *const ()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &RefCell<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &mut RefCell<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
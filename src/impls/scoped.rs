@@ -0,0 +1,77 @@
+use {
+    crate::{
+        GuardedTarget,
+        ReadApi,
+        UpgradableReadApi,
+        UpgradableScope,
+        WithReadApi,
+        WithUpgradableApi,
+        WithWriteApi,
+        WriteApi,
+    },
+    std::{cell::RefCell, thread::LocalKey},
+};
+
+impl<L: ReadApi + ?Sized> WithReadApi for L
+{
+    #[inline]
+    fn with_read<R, F>(&self, f: F) -> R
+        where F: FnOnce(&L::Target) -> R
+    {
+        f(&self.read())
+    }
+}
+
+impl<L: WriteApi + ?Sized> WithWriteApi for L
+{
+    #[inline]
+    fn with_write<R, F>(&mut self, f: F) -> R
+        where F: FnOnce(&mut L::Target) -> R
+    {
+        f(&mut self.write())
+    }
+}
+
+impl<L: UpgradableReadApi + ?Sized> WithUpgradableApi for L
+{
+    #[inline]
+    fn with_upgradable<R, F>(&mut self, f: F) -> R
+        where F: FnOnce(&mut UpgradableScope<'_, L::Target>) -> R
+    {
+        f(&mut UpgradableScope::new(&mut self.upgradable_read()))
+    }
+}
+
+impl<T: 'static> GuardedTarget for &'static LocalKey<RefCell<T>> {
+    type Target = T;
+}
+
+impl<T: 'static> WithReadApi for &'static LocalKey<RefCell<T>>
+{
+    #[inline]
+    fn with_read<R, F>(&self, f: F) -> R
+        where F: FnOnce(&T) -> R
+    {
+        self.with(|cell| f(&cell.borrow()))
+    }
+}
+
+impl<T: 'static> WithWriteApi for &'static LocalKey<RefCell<T>>
+{
+    #[inline]
+    fn with_write<R, F>(&mut self, f: F) -> R
+        where F: FnOnce(&mut T) -> R
+    {
+        self.with(|cell| f(&mut cell.borrow_mut()))
+    }
+}
+
+impl<T: 'static> WithUpgradableApi for &'static LocalKey<RefCell<T>>
+{
+    #[inline]
+    fn with_upgradable<R, F>(&mut self, f: F) -> R
+        where F: FnOnce(&mut UpgradableScope<'_, T>) -> R
+    {
+        self.with(|cell| f(&mut UpgradableScope::new(&mut &mut *cell.borrow_mut())))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,143 @@
+use {
+    crate::{
+        GuardedTarget,
+        LockStateApi,
+        ReadApi,
+        SharedDowngradableWriteApi,
+        SharedUpgradableReadApi,
+        SharedWriteApi,
+    },
+    std::{rc::Rc, sync::Arc},
+};
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for Arc<L> {
+    type Target = L::Target;
+}
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for Rc<L> {
+    type Target = L::Target;
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for Arc<L>
+{
+    type ReadGuard<'a> = L::ReadGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        L::read(self)
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedWriteApi for Arc<L>
+{
+    type SharedWriteGuard<'a> = L::SharedWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write_shared(&self) -> L::SharedWriteGuard<'_> {
+        L::write_shared(self)
+    }
+}
+
+impl<L: SharedUpgradableReadApi + ?Sized> SharedUpgradableReadApi for Arc<L>
+{
+    type SharedUpgradableReadGuard<'a> = L::SharedUpgradableReadGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read_shared(&self) -> L::SharedUpgradableReadGuard<'_> {
+        L::upgradable_read_shared(self)
+    }
+}
+
+impl<L: SharedDowngradableWriteApi + ?Sized> SharedDowngradableWriteApi for Arc<L>
+{
+    type SharedDowngradableWriteGuard<'a> = L::SharedDowngradableWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write_shared(&self) -> L::SharedDowngradableWriteGuard<'_> {
+        L::downgradable_write_shared(self)
+    }
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for Rc<L>
+{
+    type ReadGuard<'a> = L::ReadGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        L::read(self)
+    }
+}
+
+impl<L: SharedWriteApi + ?Sized> SharedWriteApi for Rc<L>
+{
+    type SharedWriteGuard<'a> = L::SharedWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn write_shared(&self) -> L::SharedWriteGuard<'_> {
+        L::write_shared(self)
+    }
+}
+
+impl<L: SharedUpgradableReadApi + ?Sized> SharedUpgradableReadApi for Rc<L>
+{
+    type SharedUpgradableReadGuard<'a> = L::SharedUpgradableReadGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn upgradable_read_shared(&self) -> L::SharedUpgradableReadGuard<'_> {
+        L::upgradable_read_shared(self)
+    }
+}
+
+impl<L: SharedDowngradableWriteApi + ?Sized> SharedDowngradableWriteApi for Rc<L>
+{
+    type SharedDowngradableWriteGuard<'a> = L::SharedDowngradableWriteGuard<'a>
+        where Self: 'a;
+
+    #[inline(always)]
+    fn downgradable_write_shared(&self) -> L::SharedDowngradableWriteGuard<'_> {
+        L::downgradable_write_shared(self)
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for Arc<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        L::is_locked(self)
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        L::is_locked_exclusive(self)
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        L::reader_count(self)
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for Rc<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        L::is_locked(self)
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        L::is_locked_exclusive(self)
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        L::reader_count(self)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,179 @@
+use {
+    crate::{
+        DowngradableWriteApi,
+        GuardPart,
+        GuardedTarget,
+        ReadApi,
+        SplitGuard,
+        UpgradableReadApi,
+        WriteApi,
+    },
+    std::{ops::DerefMut, ptr::NonNull, rc::Rc},
+};
+
+impl<G: DerefMut> SplitGuard for G
+{
+    #[inline]
+    fn split2<A, B, F>(s: Self, f: F) -> (GuardPart<G, A>, GuardPart<G, B>)
+        where
+            A: ?Sized,
+            B: ?Sized,
+            F: FnOnce(&mut G::Target) -> (&mut A, &mut B)
+    {
+        let mut guard = Rc::new(s);
+        let (a, b) = f(Rc::get_mut(&mut guard).expect("the guard has just been wrapped"));
+        let (a, b) = (NonNull::from(a), NonNull::from(b));
+        // SAFETY: the parts are mutably borrowed from the guard at once, so they don't overlap.
+        unsafe { (GuardPart::new(a, guard.clone()), GuardPart::new(b, guard)) }
+    }
+
+    #[inline]
+    fn split3<A, B, C, F>(s: Self, f: F) -> (GuardPart<G, A>, GuardPart<G, B>, GuardPart<G, C>)
+        where
+            A: ?Sized,
+            B: ?Sized,
+            C: ?Sized,
+            F: FnOnce(&mut G::Target) -> (&mut A, &mut B, &mut C)
+    {
+        let mut guard = Rc::new(s);
+        let (a, b, c) = f(Rc::get_mut(&mut guard).expect("the guard has just been wrapped"));
+        let (a, b, c) = (NonNull::from(a), NonNull::from(b), NonNull::from(c));
+        // SAFETY: the parts are mutably borrowed from the guard at once, so they don't overlap.
+        unsafe { (GuardPart::new(a, guard.clone()), GuardPart::new(b, guard.clone()), GuardPart::new(c, guard)) }
+    }
+
+    #[inline]
+    fn split4<A, B, C, D, F>(s: Self, f: F)
+        -> (GuardPart<G, A>, GuardPart<G, B>, GuardPart<G, C>, GuardPart<G, D>)
+        where
+            A: ?Sized,
+            B: ?Sized,
+            C: ?Sized,
+            D: ?Sized,
+            F: FnOnce(&mut G::Target) -> (&mut A, &mut B, &mut C, &mut D)
+    {
+        let mut guard = Rc::new(s);
+        let (a, b, c, d) = f(Rc::get_mut(&mut guard).expect("the guard has just been wrapped"));
+        let (a, b, c, d) = (NonNull::from(a), NonNull::from(b), NonNull::from(c), NonNull::from(d));
+        // SAFETY: the parts are mutably borrowed from the guard at once, so they don't overlap.
+        unsafe {
+            (
+                GuardPart::new(a, guard.clone()),
+                GuardPart::new(b, guard.clone()),
+                GuardPart::new(c, guard.clone()),
+                GuardPart::new(d, guard),
+            )
+        }
+    }
+}
+
+impl<G, U: ?Sized> GuardedTarget for GuardPart<G, U> {
+    type Target = U;
+}
+
+impl<G, U: ?Sized> GuardedTarget for &GuardPart<G, U> {
+    type Target = U;
+}
+
+impl<G, U: ?Sized> GuardedTarget for &mut GuardPart<G, U> {
+    type Target = U;
+}
+
+impl<G, U: ?Sized> ReadApi for GuardPart<G, U>
+{
+    type ReadGuard<'i> = &'i U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &U {
+        self.get()
+    }
+}
+
+impl<G, U: ?Sized> ReadApi for &GuardPart<G, U>
+{
+    type ReadGuard<'i> = &'i U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &U {
+        self.get()
+    }
+}
+
+impl<G, U: ?Sized> ReadApi for &mut GuardPart<G, U>
+{
+    type ReadGuard<'i> = &'i U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &U {
+        self.get()
+    }
+}
+
+impl<G, U: ?Sized> UpgradableReadApi for GuardPart<G, U>
+{
+    type UpgradableReadGuard<'i> = &'i mut U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn upgradable_read(&mut self) -> &mut U {
+        self.get_mut()
+    }
+}
+
+impl<G, U: ?Sized> UpgradableReadApi for &mut GuardPart<G, U>
+{
+    type UpgradableReadGuard<'i> = &'i mut U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn upgradable_read(&mut self) -> &mut U {
+        self.get_mut()
+    }
+}
+
+impl<G, U: ?Sized> WriteApi for GuardPart<G, U>
+{
+    type WriteGuard<'i> = &'i mut U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn write(&mut self) -> &mut U {
+        self.get_mut()
+    }
+}
+
+impl<G, U: ?Sized> WriteApi for &mut GuardPart<G, U>
+{
+    type WriteGuard<'i> = &'i mut U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn write(&mut self) -> &mut U {
+        self.get_mut()
+    }
+}
+
+impl<G, U: ?Sized> DowngradableWriteApi for GuardPart<G, U>
+{
+    type DowngradableWriteGuard<'i> = &'i mut U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn downgradable_write(&mut self) -> &mut U {
+        self.get_mut()
+    }
+}
+
+impl<G, U: ?Sized> DowngradableWriteApi for &mut GuardPart<G, U>
+{
+    type DowngradableWriteGuard<'i> = &'i mut U
+        where Self: 'i;
+
+    #[inline(always)]
+    fn downgradable_write(&mut self) -> &mut U {
+        self.get_mut()
+    }
+}
\ No newline at end of file
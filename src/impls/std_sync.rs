@@ -0,0 +1,322 @@
+use {
+    crate::{
+        poison::expect_healthy,
+        GuardedTarget,
+        IntoInnerApi,
+        LockIdentityApi,
+        MappedReadGuard,
+        MappedWriteGuard,
+        MapReadGuard,
+        MapWriteGuard,
+        NewApi,
+        PoisonApi,
+        PoisonGuard,
+        Poisonable,
+        ReadApi,
+        SharedWriteApi,
+        TryLockError,
+        TryReadApi,
+        TryWriteApi,
+        WriteApi,
+    },
+    std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+impl<T: ?Sized> GuardedTarget for RwLock<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> GuardedTarget for &RwLock<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> GuardedTarget for &mut RwLock<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> ReadApi for RwLock<T>
+{
+    type ReadGuard<'a> = RwLockReadGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> WriteApi for RwLock<T>
+{
+    type WriteGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> &mut T {
+        self.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> ReadApi for &RwLock<T>
+{
+    type ReadGuard<'a> = RwLockReadGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> WriteApi for &RwLock<T>
+{
+    type WriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> ReadApi for &mut RwLock<T>
+{
+    type ReadGuard<'a> = RwLockReadGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> WriteApi for &mut RwLock<T>
+{
+    type WriteGuard<'a> = &'a mut T
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> &mut T {
+        self.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for RwLock<T>
+{
+    #[inline]
+    fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        Ok(RwLock::try_read(self)?)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for RwLock<T>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut()?)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &RwLock<T>
+{
+    #[inline]
+    fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        Ok(RwLock::try_read(self)?)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for &RwLock<T>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        Ok(RwLock::try_write(self)?)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &mut RwLock<T>
+{
+    #[inline]
+    fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        Ok(RwLock::try_read(self)?)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for &mut RwLock<T>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.get_mut()?)
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for RwLock<T>
+{
+    type SharedWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for &RwLock<T>
+{
+    type SharedWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> SharedWriteApi for &mut RwLock<T>
+{
+    type SharedWriteGuard<'a> = RwLockWriteGuard<'a, T>
+        where Self: 'a;
+
+    #[inline]
+    fn write_shared(&self) -> RwLockWriteGuard<'_, T> {
+        RwLock::write(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> NewApi for RwLock<T>
+{
+    #[inline]
+    fn from_value(value: T) -> Self {
+        RwLock::new(value)
+    }
+}
+
+impl<T> IntoInnerApi for RwLock<T>
+{
+    #[inline]
+    fn into_inner(self) -> T {
+        RwLock::into_inner(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> PoisonApi for RwLock<T>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        RwLock::is_poisoned(self)
+    }
+}
+
+impl<T: ?Sized> PoisonApi for &RwLock<T>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        RwLock::is_poisoned(self)
+    }
+}
+
+impl<T: ?Sized> PoisonApi for &mut RwLock<T>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        RwLock::is_poisoned(self)
+    }
+}
+
+impl<T> WriteApi for &Poisonable<RwLock<T>>
+{
+    type WriteGuard<'a> = PoisonGuard<'a, RwLockWriteGuard<'a, T>>
+        where Self: 'a;
+
+    #[inline]
+    fn write(&mut self) -> Self::WriteGuard<'_> {
+        let lock = *self;
+        let guard = RwLock::write(&lock.lock).unwrap_or_else(PoisonError::into_inner);
+        expect_healthy(lock.wrap(guard, true))
+    }
+}
+
+impl<T> TryWriteApi for &Poisonable<RwLock<T>>
+{
+    #[inline]
+    fn try_write(&mut self) -> Result<Self::WriteGuard<'_>, TryLockError> {
+        let lock = *self;
+        let guard = match RwLock::try_write(&lock.lock) {
+            Err(std::sync::TryLockError::Poisoned(err)) => err.into_inner(),
+            result => result?,
+        };
+        lock.wrap(guard, true)
+    }
+}
+
+impl<'a, T: ?Sized> MapReadGuard<'a> for RwLockReadGuard<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = MappedReadGuard<Self, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> MappedReadGuard<Self, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> &U
+    {
+        // SAFETY: `std` guards only point to the data inside the lock.
+        unsafe { MappedReadGuard::new(s, f) }
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<MappedReadGuard<Self, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&T) -> Option<&U>
+    {
+        // SAFETY: `std` guards only point to the data inside the lock.
+        unsafe { MappedReadGuard::try_new(s, f) }
+    }
+}
+
+impl<'a, T: ?Sized> MapWriteGuard<'a> for RwLockWriteGuard<'a, T>
+{
+    type Mapped<U: ?Sized + 'a> = MappedWriteGuard<Self, U>;
+
+    #[inline]
+    fn map<U, F>(s: Self, f: F) -> MappedWriteGuard<Self, U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> &mut U
+    {
+        // SAFETY: `std` guards only point to the data inside the lock.
+        unsafe { MappedWriteGuard::new(s, f) }
+    }
+
+    #[inline]
+    fn try_map<U, F>(s: Self, f: F) -> Result<MappedWriteGuard<Self, U>, Self>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut T) -> Option<&mut U>
+    {
+        // SAFETY: `std` guards only point to the data inside the lock.
+        unsafe { MappedWriteGuard::try_new(s, f) }
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for RwLock<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        self as *const Self as *const ()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &RwLock<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &mut RwLock<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
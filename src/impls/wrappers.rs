@@ -1,12 +1,33 @@
-use crate::{
-    DowngradableWriteApi,
-    GuardedTarget,
-    ReadApi,
-    ReadApiWrapper,
-    RwApiWrapper,
-    RwApiWrapperOwned,
-    UpgradableReadApi,
-    WriteApi,
+use {
+    crate::{
+        ClearPoisonApi,
+        DowngradableWriteApi,
+        FlatReadApiWrapper,
+        FlatRwApiWrapper,
+        GuardedTarget,
+        IntoInnerApi,
+        LockIdentityApi,
+        LockStateApi,
+        NewApi,
+        PoisonApi,
+        ReadApi,
+        ReadApiWrapper,
+        ReadApiWrapperOwned,
+        ReadOnly,
+        RecursiveReadApi,
+        RwApiWrapper,
+        RwApiWrapperOwned,
+        TimedReadApi,
+        TimedUpgradableReadApi,
+        TimedWriteApi,
+        TryLockError,
+        TryReadApi,
+        TryUpgradableReadApi,
+        TryWriteApi,
+        UpgradableReadApi,
+        WriteApi,
+    },
+    std::time::{Duration, Instant},
 };
 
 impl<'a, T: ?Sized> GuardedTarget for ReadApiWrapper<'a, T> {
@@ -33,18 +54,66 @@ impl<'a, T: ?Sized> GuardedTarget for &mut RwApiWrapper<'a, T> {
     type Target = &'a mut T;
 }
 
-impl<T> GuardedTarget for RwApiWrapperOwned<T> {
+impl<T: ?Sized> GuardedTarget for RwApiWrapperOwned<T> {
     type Target = T;
 }
 
-impl<T> GuardedTarget for &RwApiWrapperOwned<T> {
+impl<T: ?Sized> GuardedTarget for &RwApiWrapperOwned<T> {
     type Target = T;
 }
 
-impl<T> GuardedTarget for &mut RwApiWrapperOwned<T> {
+impl<T: ?Sized> GuardedTarget for &mut RwApiWrapperOwned<T> {
     type Target = T;
 }
 
+impl<'a, T: ?Sized> GuardedTarget for FlatRwApiWrapper<'a, T> {
+    type Target = T;
+}
+
+impl<'a, T: ?Sized> GuardedTarget for &FlatRwApiWrapper<'a, T> {
+    type Target = T;
+}
+
+impl<'a, T: ?Sized> GuardedTarget for &mut FlatRwApiWrapper<'a, T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> GuardedTarget for ReadApiWrapperOwned<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> GuardedTarget for &ReadApiWrapperOwned<T> {
+    type Target = T;
+}
+
+impl<T: ?Sized> GuardedTarget for &mut ReadApiWrapperOwned<T> {
+    type Target = T;
+}
+
+impl<'a, T: ?Sized> GuardedTarget for FlatReadApiWrapper<'a, T> {
+    type Target = T;
+}
+
+impl<'a, T: ?Sized> GuardedTarget for &FlatReadApiWrapper<'a, T> {
+    type Target = T;
+}
+
+impl<'a, T: ?Sized> GuardedTarget for &mut FlatReadApiWrapper<'a, T> {
+    type Target = T;
+}
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for ReadOnly<L> {
+    type Target = L::Target;
+}
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for &ReadOnly<L> {
+    type Target = L::Target;
+}
+
+impl<L: GuardedTarget + ?Sized> GuardedTarget for &mut ReadOnly<L> {
+    type Target = L::Target;
+}
+
 impl<'a, T: ?Sized> ReadApi for ReadApiWrapper<'a, T>
 {
     type ReadGuard<'i> = &'i &'a T
@@ -177,7 +246,73 @@ impl<'a, T: ?Sized> DowngradableWriteApi for &mut RwApiWrapper<'a, T>
     }
 }
 
-impl<T> ReadApi for RwApiWrapperOwned<T>
+impl<T: ?Sized> ReadApi for RwApiWrapperOwned<T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> ReadApi for &RwApiWrapperOwned<T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> ReadApi for &mut RwApiWrapperOwned<T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'a, T: ?Sized> ReadApi for FlatRwApiWrapper<'a, T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> ReadApi for &FlatRwApiWrapper<'a, T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> ReadApi for &mut FlatRwApiWrapper<'a, T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        self.0
+    }
+}
+
+impl<T: ?Sized> ReadApi for ReadApiWrapperOwned<T>
 {
     type ReadGuard<'i> = &'i T
         where Self: 'i;
@@ -188,7 +323,7 @@ impl<T> ReadApi for RwApiWrapperOwned<T>
     }
 }
 
-impl<T> ReadApi for &RwApiWrapperOwned<T>
+impl<T: ?Sized> ReadApi for &ReadApiWrapperOwned<T>
 {
     type ReadGuard<'i> = &'i T
         where Self: 'i;
@@ -199,7 +334,7 @@ impl<T> ReadApi for &RwApiWrapperOwned<T>
     }
 }
 
-impl<T> ReadApi for &mut RwApiWrapperOwned<T>
+impl<T: ?Sized> ReadApi for &mut ReadApiWrapperOwned<T>
 {
     type ReadGuard<'i> = &'i T
         where Self: 'i;
@@ -210,7 +345,73 @@ impl<T> ReadApi for &mut RwApiWrapperOwned<T>
     }
 }
 
-impl<T> UpgradableReadApi for RwApiWrapperOwned<T>
+impl<'a, T: ?Sized> ReadApi for FlatReadApiWrapper<'a, T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> ReadApi for &FlatReadApiWrapper<'a, T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> ReadApi for &mut FlatReadApiWrapper<'a, T>
+{
+    type ReadGuard<'i> = &'i T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> &T {
+        self.0
+    }
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for ReadOnly<L>
+{
+    type ReadGuard<'i> = L::ReadGuard<'i>
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        self.0.read()
+    }
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for &ReadOnly<L>
+{
+    type ReadGuard<'i> = L::ReadGuard<'i>
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        self.0.read()
+    }
+}
+
+impl<L: ReadApi + ?Sized> ReadApi for &mut ReadOnly<L>
+{
+    type ReadGuard<'i> = L::ReadGuard<'i>
+        where Self: 'i;
+
+    #[inline(always)]
+    fn read(&self) -> L::ReadGuard<'_> {
+        self.0.read()
+    }
+}
+
+impl<T: ?Sized> UpgradableReadApi for RwApiWrapperOwned<T>
 {
     type UpgradableReadGuard<'i> = &'i mut T
         where Self: 'i;
@@ -221,7 +422,7 @@ impl<T> UpgradableReadApi for RwApiWrapperOwned<T>
     }
 }
 
-impl<T> UpgradableReadApi for &mut RwApiWrapperOwned<T>
+impl<T: ?Sized> UpgradableReadApi for &mut RwApiWrapperOwned<T>
 {
     type UpgradableReadGuard<'i> = &'i mut T
         where Self: 'i;
@@ -232,7 +433,29 @@ impl<T> UpgradableReadApi for &mut RwApiWrapperOwned<T>
     }
 }
 
-impl<T> WriteApi for RwApiWrapperOwned<T>
+impl<'a, T: ?Sized> UpgradableReadApi for FlatRwApiWrapper<'a, T>
+{
+    type UpgradableReadGuard<'i> = &'i mut T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn upgradable_read(&mut self) -> &mut T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> UpgradableReadApi for &mut FlatRwApiWrapper<'a, T>
+{
+    type UpgradableReadGuard<'i> = &'i mut T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn upgradable_read(&mut self) -> &mut T {
+        self.0
+    }
+}
+
+impl<T: ?Sized> WriteApi for RwApiWrapperOwned<T>
 {
     type WriteGuard<'i> = &'i mut T
         where Self: 'i;
@@ -243,7 +466,7 @@ impl<T> WriteApi for RwApiWrapperOwned<T>
     }
 }
 
-impl<T> WriteApi for &mut RwApiWrapperOwned<T>
+impl<T: ?Sized> WriteApi for &mut RwApiWrapperOwned<T>
 {
     type WriteGuard<'i> = &'i mut T
         where Self: 'i;
@@ -254,7 +477,29 @@ impl<T> WriteApi for &mut RwApiWrapperOwned<T>
     }
 }
 
-impl<T> DowngradableWriteApi for RwApiWrapperOwned<T>
+impl<'a, T: ?Sized> WriteApi for FlatRwApiWrapper<'a, T>
+{
+    type WriteGuard<'i> = &'i mut T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn write(&mut self) -> &mut T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> WriteApi for &mut FlatRwApiWrapper<'a, T>
+{
+    type WriteGuard<'i> = &'i mut T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn write(&mut self) -> &mut T {
+        self.0
+    }
+}
+
+impl<T: ?Sized> DowngradableWriteApi for RwApiWrapperOwned<T>
 {
     type DowngradableWriteGuard<'i> = &'i mut T
         where Self: 'i;
@@ -265,7 +510,7 @@ impl<T> DowngradableWriteApi for RwApiWrapperOwned<T>
     }
 }
 
-impl<T> DowngradableWriteApi for &mut RwApiWrapperOwned<T>
+impl<T: ?Sized> DowngradableWriteApi for &mut RwApiWrapperOwned<T>
 {
     type DowngradableWriteGuard<'i> = &'i mut T
         where Self: 'i;
@@ -274,4 +519,2002 @@ impl<T> DowngradableWriteApi for &mut RwApiWrapperOwned<T>
     fn downgradable_write(&mut self) -> &mut T {
         &mut self.0
     }
-}
\ No newline at end of file
+}
+
+impl<'a, T: ?Sized> DowngradableWriteApi for FlatRwApiWrapper<'a, T>
+{
+    type DowngradableWriteGuard<'i> = &'i mut T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn downgradable_write(&mut self) -> &mut T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> DowngradableWriteApi for &mut FlatRwApiWrapper<'a, T>
+{
+    type DowngradableWriteGuard<'i> = &'i mut T
+        where Self: 'i;
+
+    #[inline(always)]
+    fn downgradable_write(&mut self) -> &mut T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &mut ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryUpgradableReadApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryUpgradableReadApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryWriteApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryWriteApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TryReadApi for &mut ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryReadApi for &mut FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<L: TryReadApi + ?Sized> TryReadApi for ReadOnly<L>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read()
+    }
+}
+
+impl<L: TryReadApi + ?Sized> TryReadApi for &ReadOnly<L>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read()
+    }
+}
+
+impl<L: TryReadApi + ?Sized> TryReadApi for &mut ReadOnly<L>
+{
+    #[inline(always)]
+    fn try_read(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read()
+    }
+}
+
+impl<T: ?Sized> TryUpgradableReadApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<T: ?Sized> TryUpgradableReadApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryUpgradableReadApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryUpgradableReadApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<T: ?Sized> TryWriteApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryWriteApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TryWriteApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write(&mut self) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &mut ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&&'a T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&&'a mut T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedUpgradableReadApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, _: Duration) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, _: Instant) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedUpgradableReadApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, _: Duration) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, _: Instant) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedWriteApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedWriteApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut &'a mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for &RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for &ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<T: ?Sized> TimedReadApi for &mut ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(&self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedReadApi for &mut FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_read_for(&self, _: Duration) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, _: Instant) -> Result<&T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<L: TimedReadApi + ?Sized> TimedReadApi for ReadOnly<L>
+{
+    #[inline(always)]
+    fn try_read_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_until(deadline)
+    }
+}
+
+impl<L: TimedReadApi + ?Sized> TimedReadApi for &ReadOnly<L>
+{
+    #[inline(always)]
+    fn try_read_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_until(deadline)
+    }
+}
+
+impl<L: TimedReadApi + ?Sized> TimedReadApi for &mut ReadOnly<L>
+{
+    #[inline(always)]
+    fn try_read_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_until(deadline)
+    }
+}
+
+impl<T: ?Sized> TimedUpgradableReadApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<T: ?Sized> TimedUpgradableReadApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedUpgradableReadApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedUpgradableReadApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_upgradable_read_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_upgradable_read_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &&'a T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&&'a T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&&'a T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&&'a T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &&'a T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&&'a T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&&'a T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&&'a T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &mut ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &&'a T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&&'a T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&&'a T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&&'a T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &&'a mut T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&&'a mut T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&&'a mut T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&&'a mut T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &&'a mut T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&&'a mut T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&&'a mut T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&&'a mut T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &&'a mut T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&&'a mut T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&&'a mut T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&&'a mut T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for &RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for &ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<T: ?Sized> RecursiveReadApi for &mut ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<'a, T: ?Sized> RecursiveReadApi for &mut FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> &T {
+        self.read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<&T, TryLockError> {
+        self.try_read()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<&T, TryLockError> {
+        self.try_read_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<&T, TryLockError> {
+        self.try_read_until(deadline)
+    }
+}
+
+impl<L: RecursiveReadApi + ?Sized> RecursiveReadApi for ReadOnly<L>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> L::ReadGuard<'_> {
+        self.0.read_recursive()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive_until(deadline)
+    }
+}
+
+impl<L: RecursiveReadApi + ?Sized> RecursiveReadApi for &ReadOnly<L>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> L::ReadGuard<'_> {
+        self.0.read_recursive()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive_until(deadline)
+    }
+}
+
+impl<L: RecursiveReadApi + ?Sized> RecursiveReadApi for &mut ReadOnly<L>
+{
+    #[inline(always)]
+    fn read_recursive(&self) -> L::ReadGuard<'_> {
+        self.0.read_recursive()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive(&self) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive()
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive_for(timeout)
+    }
+
+    #[inline(always)]
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<L::ReadGuard<'_>, TryLockError> {
+        self.0.try_read_recursive_until(deadline)
+    }
+}
+
+impl<T: ?Sized> TimedWriteApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<T: ?Sized> TimedWriteApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(&mut self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedWriteApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> TimedWriteApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn try_write_for(&mut self, _: Duration) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+
+    #[inline(always)]
+    fn try_write_until(&mut self, _: Instant) -> Result<&mut T, TryLockError> {
+        Ok(self.0)
+    }
+}
+
+impl<'a, T: ?Sized> PoisonApi for ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &mut ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &mut ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T> NewApi for RwApiWrapperOwned<T>
+{
+    #[inline]
+    fn from_value(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> IntoInnerApi for RwApiWrapperOwned<T>
+{
+    #[inline]
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> NewApi for ReadApiWrapperOwned<T>
+{
+    #[inline]
+    fn from_value(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> IntoInnerApi for ReadApiWrapperOwned<T>
+{
+    #[inline]
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<L: NewApi> NewApi for ReadOnly<L>
+    where L::Target: Sized
+{
+    #[inline]
+    fn from_value(value: L::Target) -> Self {
+        Self(L::from_value(value))
+    }
+}
+
+impl<L: IntoInnerApi> IntoInnerApi for ReadOnly<L>
+    where L::Target: Sized
+{
+    #[inline]
+    fn into_inner(self) -> L::Target {
+        self.0.into_inner()
+    }
+}
+
+impl<T: ?Sized> PoisonApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<T: ?Sized> PoisonApi for &mut ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> ClearPoisonApi for &mut ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<'a, T: ?Sized> PoisonApi for &mut FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T: ?Sized> ClearPoisonApi for &mut FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {}
+}
+
+impl<L: PoisonApi + ?Sized> PoisonApi for ReadOnly<L>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        self.0.is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi + ?Sized> ClearPoisonApi for ReadOnly<L>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {
+        self.0.clear_poison()
+    }
+}
+
+impl<L: PoisonApi + ?Sized> PoisonApi for &ReadOnly<L>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        self.0.is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi + ?Sized> ClearPoisonApi for &ReadOnly<L>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {
+        self.0.clear_poison()
+    }
+}
+
+impl<L: PoisonApi + ?Sized> PoisonApi for &mut ReadOnly<L>
+{
+    #[inline(always)]
+    fn is_poisoned(&self) -> bool {
+        self.0.is_poisoned()
+    }
+}
+
+impl<L: ClearPoisonApi + ?Sized> ClearPoisonApi for &mut ReadOnly<L>
+{
+    #[inline(always)]
+    fn clear_poison(&self) {
+        self.0.clear_poison()
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &mut ReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<T: ?Sized> LockStateApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<T: ?Sized> LockStateApi for ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<T: ?Sized> LockStateApi for &mut ReadApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<'a, T: ?Sized> LockStateApi for &mut FlatReadApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for ReadOnly<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        self.0.is_locked_exclusive()
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        self.0.reader_count()
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for &ReadOnly<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        self.0.is_locked_exclusive()
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        self.0.reader_count()
+    }
+}
+
+impl<L: LockStateApi + ?Sized> LockStateApi for &mut ReadOnly<L>
+{
+    #[inline(always)]
+    fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+
+    #[inline(always)]
+    fn is_locked_exclusive(&self) -> bool {
+        self.0.is_locked_exclusive()
+    }
+
+    #[inline(always)]
+    fn reader_count(&self) -> Option<usize> {
+        self.0.reader_count()
+    }
+}
+
+impl<'a, T: ?Sized> LockIdentityApi for RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        &*self.0 as *const T as *const ()
+    }
+}
+
+impl<'a, T: ?Sized> LockIdentityApi for &RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<'a, T: ?Sized> LockIdentityApi for &mut RwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        &self.0 as *const T as *const ()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<T: ?Sized> LockIdentityApi for &mut RwApiWrapperOwned<T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<'a, T: ?Sized> LockIdentityApi for FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        &*self.0 as *const T as *const ()
+    }
+}
+
+impl<'a, T: ?Sized> LockIdentityApi for &FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
+
+impl<'a, T: ?Sized> LockIdentityApi for &mut FlatRwApiWrapper<'a, T>
+{
+    #[inline(always)]
+    fn lock_addr(&self) -> *const () {
+        (**self).lock_addr()
+    }
+}
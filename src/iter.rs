@@ -0,0 +1,386 @@
+use {
+    crate::{GuardedTarget, ReadApi, UpgradableReadApi, WriteApi},
+    std::{
+        fmt,
+        iter::FusedIterator,
+        ops::{Deref, DerefMut},
+        rc::Rc,
+    },
+};
+
+/// Provides guard-holding iterators over [`ReadApi`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use parking_lot::RwLock;
+/// use read_write_api::{IterReadApiExt, ReadApi, ReadIter, RwApiWrapperOwned};
+///
+/// fn evens<L>(x: &L) -> impl Iterator<Item=u64> + '_
+///     where L: ReadApi<Target=Vec<u64>>
+/// {
+///     x.read_iter().map(|item| *item).filter(|item| item % 2 == 0)
+/// }
+///
+/// fn lock_iter(x: &RwLock<Vec<u64>>) -> ReadIter<'_, RwLock<Vec<u64>>> {
+///     x.read_iter()
+/// }
+///
+/// let lock = RwLock::new(vec![1, 2, 4]);
+/// assert_eq!(evens(&lock).collect::<Vec<_>>(), [2, 4]);
+/// assert_eq!(evens(&RwApiWrapperOwned(vec![6, 7])).collect::<Vec<_>>(), [6]);
+///
+/// let first = lock_iter(&lock).next().unwrap();
+/// assert!(lock.try_write().is_none());
+/// assert_eq!(*first, 1);
+/// drop(first);
+/// assert!(lock.try_write().is_some());
+///
+/// let map = RwLock::new(HashMap::from([("a", 1)]));
+/// let entry = map.read_iter().next().unwrap();
+/// assert_eq!((*entry.key(), *entry.value()), ("a", 1));
+/// ```
+pub trait IterReadApiExt: ReadApi
+{
+    /// Returns an iterator over the guarded collection, which holds a read guard.
+    ///
+    /// Every item holds the guard as well, so the lock is released
+    /// only after both the iterator and all of its items are dropped.
+    fn read_iter<'a>(&'a self) -> ReadIter<'a, Self>
+        where &'a Self::Target: IntoIterator;
+}
+
+/// Provides guard-holding iterators over [`UpgradableReadApi`].
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     IterUpgradableReadApiExt,
+///     RwApiWrapperOwned,
+///     UpgradableReadApi,
+///     UpgradableReadGuard,
+/// };
+///
+/// fn dedup_sorted(mut x: impl UpgradableReadApi<Target=Vec<u64>>) -> usize {
+///     let mut iter = x.upgradable_read_iter();
+///     let mut prev = None;
+///     let has_duplicates = iter.any(|item| prev.replace(*item) == Some(*item));
+///     let mut guard = iter.into_guard().unwrap();
+///     if has_duplicates {
+///         guard.with_upgraded(Vec::dedup)
+///     }
+///     guard.len()
+/// }
+///
+/// let lock = RwLock::new(vec![1, 1, 2]);
+/// assert_eq!(dedup_sorted(&lock), 2);
+/// assert_eq!(*lock.read(), [1, 2]);
+///
+/// let mut lock_ref = &lock;
+/// let first = lock_ref.upgradable_read_iter().next().unwrap();
+/// assert!(lock.try_read().is_some());
+/// assert!(lock.try_upgradable_read().is_none());
+/// drop(first);
+///
+/// assert_eq!(dedup_sorted(&mut RwApiWrapperOwned(vec![3, 4])), 2);
+/// ```
+pub trait IterUpgradableReadApiExt: UpgradableReadApi
+{
+    /// Returns an iterator over the guarded collection, which holds an upgradable read guard.
+    ///
+    /// Every item holds the guard as well, so the lock is released
+    /// only after both the iterator and all of its items are dropped.
+    /// Once they are, [`GuardedIter::into_guard`] hands the guard back for upgrading.
+    fn upgradable_read_iter<'a>(&'a mut self) -> UpgradableReadIter<'a, Self>
+        where &'a Self::Target: IntoIterator;
+}
+
+/// Provides guard-holding iterators over [`WriteApi`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::{BTreeSet, HashMap};
+/// use parking_lot::RwLock;
+/// use read_write_api::{IterWriteApiExt, RwApiWrapperOwned, WriteApi};
+///
+/// fn double(mut x: impl WriteApi<Target=Vec<u64>>) {
+///     for mut item in x.write_iter_mut() {
+///         *item *= 2
+///     }
+/// }
+///
+/// let lock = RwLock::new(vec![1, 2]);
+/// double(&lock);
+/// assert_eq!(*lock.read(), [2, 4]);
+///
+/// let mut wrapper = RwApiWrapperOwned(vec![3]);
+/// double(&mut wrapper);
+/// assert_eq!(wrapper.0, [6]);
+///
+/// let map = RwLock::new(HashMap::from([("a", 1)]));
+/// for mut entry in (&map).write_iter_mut() {
+///     *entry.value_mut() += 1
+/// }
+/// assert_eq!(map.read()["a"], 2);
+///
+/// let mut set = RwApiWrapperOwned(BTreeSet::from([2, 1]));
+/// assert_eq!(set.drain_locked().collect::<Vec<_>>(), [1, 2]);
+/// assert!(set.0.is_empty());
+/// ```
+pub trait IterWriteApiExt: WriteApi
+{
+    /// Returns an iterator over the guarded collection, which holds a write guard.
+    ///
+    /// Every item holds the guard as well, so the lock is released
+    /// only after both the iterator and all of its items are dropped.
+    fn write_iter_mut<'a>(&'a mut self) -> WriteIter<'a, Self>
+        where &'a mut Self::Target: IntoIterator;
+
+    /// Returns an iterator removing all elements from the guarded collection,
+    /// which holds a write guard.
+    fn drain_locked(&mut self) -> DrainLocked<Self::WriteGuard<'_>, <Self::Target as DrainTarget>::Drain<'_>>
+        where Self::Target: DrainTarget;
+}
+
+/// Collection, which can be drained by [`IterWriteApiExt::drain_locked`].
+///
+/// Implemented for [`Vec`], [`VecDeque`](std::collections::VecDeque),
+/// [`BinaryHeap`](std::collections::BinaryHeap), std maps and sets
+/// and, with the `indexmap` feature, for `IndexMap` and `IndexSet`.
+pub trait DrainTarget
+{
+    /// Type of the drained elements.
+    type Item;
+
+    /// Draining iterator.
+    type Drain<'a>: Iterator<Item=Self::Item>
+        where Self: 'a;
+
+    /// Removes all elements from the collection, returning them as an iterator.
+    fn drain_all(&mut self) -> Self::Drain<'_>;
+}
+
+/// [`GuardedIter`] returned by [`IterReadApiExt::read_iter`].
+pub type ReadIter<'a, L> = GuardedIter<
+    <L as ReadApi>::ReadGuard<'a>,
+    <&'a <L as GuardedTarget>::Target as IntoIterator>::IntoIter
+>;
+
+/// [`GuardedIter`] returned by [`IterUpgradableReadApiExt::upgradable_read_iter`].
+pub type UpgradableReadIter<'a, L> = GuardedIter<
+    <L as UpgradableReadApi>::UpgradableReadGuard<'a>,
+    <&'a <L as GuardedTarget>::Target as IntoIterator>::IntoIter
+>;
+
+/// [`GuardedIter`] returned by [`IterWriteApiExt::write_iter_mut`].
+pub type WriteIter<'a, L> = GuardedIter<
+    <L as WriteApi>::WriteGuard<'a>,
+    <&'a mut <L as GuardedTarget>::Target as IntoIterator>::IntoIter
+>;
+
+/// Iterator `I` over the data of the guard `G`, which holds the guard.
+///
+/// Yields [`GuardedItem`]s, each of which holds the guard as well.
+pub struct GuardedIter<G, I> {
+    iter: I,
+    guard: Rc<G>,
+}
+
+/// Item of a [`GuardedIter`], which holds its guard `G`.
+///
+/// Dereferences to the item for `&T` and `&mut T` items
+/// and provides access to the key and the value for map entries.
+pub struct GuardedItem<G, I> {
+    item: I,
+    _guard: Rc<G>,
+}
+
+/// Draining iterator `I` over the data of the guard `G`, which holds the guard.
+///
+/// Returned by [`IterWriteApiExt::drain_locked`].
+pub struct DrainLocked<G, I> {
+    iter: I,
+    _guard: Rc<G>,
+}
+
+impl<G: Deref, I> GuardedIter<G, I>
+{
+    /// `f` must return an iterator borrowing only the data of `guard`.
+    #[inline]
+    pub(crate) unsafe fn new<F>(guard: G, f: F) -> Self
+        where F: FnOnce(*const G::Target) -> I
+    {
+        let guard = Rc::new(guard);
+        let iter = f(&**guard);
+        Self { iter, guard }
+    }
+}
+
+impl<G, I> GuardedIter<G, I>
+{
+    /// Drops the iterator and returns its guard,
+    /// unless some of the yielded items are still alive.
+    #[inline]
+    pub fn into_guard(self) -> Option<G> {
+        let Self { iter, guard } = self;
+        drop(iter);
+        Rc::try_unwrap(guard).ok()
+    }
+}
+
+impl<G: DerefMut, I> GuardedIter<G, I>
+{
+    /// `f` must return an iterator borrowing only the data of `guard`.
+    #[inline]
+    pub(crate) unsafe fn new_mut<F>(guard: G, f: F) -> Self
+        where F: FnOnce(*mut G::Target) -> I
+    {
+        let mut guard = Rc::new(guard);
+        let iter = f(&mut **Rc::get_mut(&mut guard).expect("the guard has just been wrapped"));
+        Self { iter, guard }
+    }
+}
+
+impl<G: DerefMut, I> DrainLocked<G, I>
+{
+    /// `f` must return an iterator borrowing only the data of `guard`.
+    #[inline]
+    pub(crate) unsafe fn new<F>(guard: G, f: F) -> Self
+        where F: FnOnce(*mut G::Target) -> I
+    {
+        let mut guard = Rc::new(guard);
+        let iter = f(&mut **Rc::get_mut(&mut guard).expect("the guard has just been wrapped"));
+        Self { iter, _guard: guard }
+    }
+}
+
+impl<G, I: Iterator> Iterator for GuardedIter<G, I>
+{
+    type Item = GuardedItem<G, I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some(GuardedItem { item, _guard: self.guard.clone() })
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<G, I: DoubleEndedIterator> DoubleEndedIterator for GuardedIter<G, I>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some(GuardedItem { item, _guard: self.guard.clone() })
+    }
+}
+
+impl<G, I: ExactSizeIterator> ExactSizeIterator for GuardedIter<G, I> {}
+
+impl<G, I: FusedIterator> FusedIterator for GuardedIter<G, I> {}
+
+impl<G, I: Iterator> Iterator for DrainLocked<G, I>
+{
+    type Item = I::Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<G, I: DoubleEndedIterator> DoubleEndedIterator for DrainLocked<G, I>
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<G, I: ExactSizeIterator> ExactSizeIterator for DrainLocked<G, I> {}
+
+impl<G, I: FusedIterator> FusedIterator for DrainLocked<G, I> {}
+
+impl<G, T: ?Sized> Deref for GuardedItem<G, &T>
+{
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.item
+    }
+}
+
+impl<G, T: ?Sized> Deref for GuardedItem<G, &mut T>
+{
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.item
+    }
+}
+
+impl<G, T: ?Sized> DerefMut for GuardedItem<G, &mut T>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        self.item
+    }
+}
+
+impl<G, K: ?Sized, V: ?Sized> GuardedItem<G, (&K, &V)>
+{
+    /// Returns the key of the entry.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        self.item.0
+    }
+
+    /// Returns the value of the entry.
+    #[inline(always)]
+    pub fn value(&self) -> &V {
+        self.item.1
+    }
+}
+
+impl<G, K: ?Sized, V: ?Sized> GuardedItem<G, (&K, &mut V)>
+{
+    /// Returns the key of the entry.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        self.item.0
+    }
+
+    /// Returns the value of the entry.
+    #[inline(always)]
+    pub fn value(&self) -> &V {
+        self.item.1
+    }
+
+    /// Returns the mutable value of the entry.
+    #[inline(always)]
+    pub fn value_mut(&mut self) -> &mut V {
+        self.item.1
+    }
+}
+
+impl<G, I: fmt::Debug> fmt::Debug for GuardedItem<G, I>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.item, f)
+    }
+}
\ No newline at end of file
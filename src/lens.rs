@@ -0,0 +1,179 @@
+use {
+    crate::GuardedTarget,
+    std::{
+        fmt,
+        ops::{Deref, DerefMut},
+    },
+};
+
+/// Projects `S` onto one of its parts.
+///
+/// Implemented for pairs of accessor functions `(get, get_mut)`.
+pub trait Lens<S: ?Sized>
+{
+    /// Type of the projected part.
+    type Field: ?Sized;
+
+    /// Projects a reference to `source`.
+    fn get<'s>(&self, source: &'s S) -> &'s Self::Field;
+
+    /// Projects a mutable reference to `source`.
+    fn get_mut<'s>(&self, source: &'s mut S) -> &'s mut Self::Field;
+}
+
+/// [`RwApi`](crate::RwApi) adapter projecting the data guarded by `L` through the lens `Z`.
+///
+/// Implements every access trait implemented by `L` by projecting its guards,
+/// so upgrades and downgrades work through the projection.
+/// Lenses of lenses are [`Lensed`] too.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     DowngradableWriteApi,
+///     DowngradableWriteGuard,
+///     Lensed,
+///     ReadApi,
+///     RwApi,
+///     RwApiWrapperOwned,
+///     UpgradableReadApi,
+///     UpgradableReadGuard,
+///     WriteApi,
+/// };
+///
+/// struct AppState {
+///     settings: Settings,
+/// }
+///
+/// struct Settings {
+///     volume: u8,
+/// }
+///
+/// fn louder(mut settings: impl RwApi<Target=Settings>) -> u8 {
+///     {
+///         let guard = settings.upgradable_read();
+///         if guard.volume < 10 {
+///             guard.upgrade().volume += 1
+///         }
+///     }
+///     let mut guard = settings.downgradable_write();
+///     guard.volume *= 2;
+///     guard.downgrade().volume
+/// }
+///
+/// fn settings<L>(state: L) -> impl RwApi<Target=Settings>
+///     where L: RwApi<Target=AppState>
+/// {
+///     Lensed::from_fns(state, |s| &s.settings, |s| &mut s.settings)
+/// }
+///
+/// let lock = RwLock::new(AppState { settings: Settings { volume: 1 } });
+/// assert_eq!(louder(settings(&lock)), 4);
+///
+/// let mut wrapper = RwApiWrapperOwned(AppState { settings: Settings { volume: 1 } });
+/// assert_eq!(louder(settings(&mut wrapper)), 4);
+///
+/// let mut volume = Lensed::from_fns(settings(&lock), |s| &s.volume, |s| &mut s.volume);
+/// *volume.write() = 7;
+/// assert_eq!(*volume.read(), 7);
+/// assert_eq!(lock.read().settings.volume, 7);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Lensed<L, Z> {
+    inner: L,
+    lens: Z,
+}
+
+/// Guard `G` projected through the lens `Z`.
+///
+/// Returned by the [`Lensed`] access methods.
+pub struct LensGuard<'a, G, Z> {
+    guard: G,
+    lens: &'a Z,
+}
+
+impl<L, Z> Lensed<L, Z>
+{
+    /// Creates an adapter projecting the data guarded by `inner` through the `lens`.
+    #[inline(always)]
+    pub fn new(inner: L, lens: Z) -> Self {
+        Self { inner, lens }
+    }
+
+    /// Returns the wrapped [`RwApi`](crate::RwApi) implementor.
+    #[inline(always)]
+    pub fn into_inner(self) -> L {
+        self.inner
+    }
+
+    #[inline(always)]
+    pub(crate) fn parts(&self) -> (&L, &Z) {
+        (&self.inner, &self.lens)
+    }
+
+    #[inline(always)]
+    pub(crate) fn parts_mut(&mut self) -> (&mut L, &Z) {
+        (&mut self.inner, &self.lens)
+    }
+}
+
+impl<L: GuardedTarget, R, W> Lensed<L, (R, W)>
+{
+    /// Creates an adapter projecting the data guarded by `inner`
+    /// through the `get` and `get_mut` accessors.
+    #[inline(always)]
+    pub fn from_fns<U>(inner: L, get: R, get_mut: W) -> Self
+        where
+            U: ?Sized,
+            R: Fn(&L::Target) -> &U,
+            W: Fn(&mut L::Target) -> &mut U
+    {
+        Self { inner, lens: (get, get_mut) }
+    }
+}
+
+impl<'a, G, Z> LensGuard<'a, G, Z>
+{
+    #[inline(always)]
+    pub(crate) fn new(guard: G, lens: &'a Z) -> Self {
+        Self { guard, lens }
+    }
+
+    #[inline(always)]
+    pub(crate) fn into_parts(self) -> (G, &'a Z) {
+        (self.guard, self.lens)
+    }
+
+    #[inline(always)]
+    pub(crate) fn parts_mut(&mut self) -> (&mut G, &'a Z) {
+        (&mut self.guard, self.lens)
+    }
+}
+
+impl<G: Deref, Z: Lens<G::Target>> Deref for LensGuard<'_, G, Z>
+{
+    type Target = Z::Field;
+
+    #[inline(always)]
+    fn deref(&self) -> &Z::Field {
+        self.lens.get(&self.guard)
+    }
+}
+
+impl<G: DerefMut, Z: Lens<G::Target>> DerefMut for LensGuard<'_, G, Z>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Z::Field {
+        self.lens.get_mut(&mut self.guard)
+    }
+}
+
+impl<G: Deref, Z: Lens<G::Target>> fmt::Debug for LensGuard<'_, G, Z>
+    where Z::Field: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
\ No newline at end of file
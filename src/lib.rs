@@ -1,8 +1,74 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    mem::{self, ManuallyDrop},
+    ops::{Deref, DerefMut},
+    process,
+    ptr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-pub use wrappers::{ReadApiWrapper, RwApiWrapper, RwApiWrapperOwned};
+pub use {
+    any::{AnyGuard, AnyRwApi},
+    dynamic::{
+        DynDowngradableGuard,
+        DynDowngradableWriteApi,
+        DynDowngradableWriteGuard,
+        DynReadApi,
+        DynReadGuard,
+        DynRwApi,
+        DynUpgradableGuard,
+        DynUpgradableReadApi,
+        DynUpgradableReadGuard,
+        DynWriteApi,
+        DynWriteGuard,
+    },
+    emulated::{Emulated, EmulatedReadGuard, EmulatedWriteGuard},
+    error::TryLockError,
+    ext::{swap, DowngradedGuard, ReadApiExt, RwApiExt, UpgradableReadApiExt},
+    iter::{
+        DrainLocked,
+        DrainTarget,
+        GuardedItem,
+        GuardedIter,
+        IterReadApiExt,
+        IterUpgradableReadApiExt,
+        IterWriteApiExt,
+        ReadIter,
+        UpgradableReadIter,
+        WriteIter,
+    },
+    lens::{Lens, LensGuard, Lensed},
+    map::{EntryGuard, GetOrInsertGuard, MapTarget, MapUpgradableReadApiExt, MapWriteApiExt},
+    mapped::{MappedReadGuard, MappedWriteGuard},
+    poison::{ClearPoisonApi, PoisonApi, PoisonGuard, Poisonable, PoisonPolicy},
+    scoped::{UpgradableScope, WithReadApi, WithUpgradableApi, WithWriteApi},
+    split::{GuardPart, SplitGuard},
+    state::{LockIdentityApi, LockStateApi},
+    wrappers::{
+        FlatReadApiWrapper,
+        FlatRwApiWrapper,
+        ReadApiWrapper,
+        ReadApiWrapperOwned,
+        ReadOnly,
+        RwApiWrapper,
+        RwApiWrapperOwned,
+    },
+};
 
+mod any;
+mod dynamic;
+mod emulated;
+mod error;
+mod ext;
 mod impls;
+mod iter;
+mod lens;
+mod map;
+mod mapped;
+mod poison;
+mod scoped;
+mod split;
+mod state;
 mod wrappers;
 
 /// Provides an interface to unify single-threaded code and RwLocks-based code.
@@ -68,8 +134,72 @@ mod wrappers;
 /// assert_eq!(do_something_ref(&mut RwLock::new(&mut 1)), 2);
 /// assert_eq!(do_something_ref(&mut RwLock::new(&mut 3)), 3);
 /// ```
+///
+/// Unsized targets, such as trait objects and slices, are supported too:
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use parking_lot::RwLock;
+/// use read_write_api::{RwApi, RwApiWrapperOwned};
+///
+/// trait Counter {
+///     fn get(&self) -> u64;
+///     fn increment(&mut self);
+/// }
+///
+/// struct Concrete(u64);
+///
+/// impl Counter for Concrete {
+///     fn get(&self) -> u64 { self.0 }
+///     fn increment(&mut self) { self.0 += 1 }
+/// }
+///
+/// fn increment(mut x: impl RwApi<Target=dyn Counter>) -> u64 {
+///     x.write().increment();
+///     x.read().get()
+/// }
+///
+/// fn zero_first(mut x: impl RwApi<Target=[u8]>) -> usize {
+///     x.write()[0] = 0;
+///     x.read().len()
+/// }
+///
+/// let lock: Arc<RwLock<dyn Counter>> = Arc::new(RwLock::new(Concrete(1)));
+/// assert_eq!(increment(&*lock), 2);
+/// assert_eq!(increment(&*lock), 3);
+///
+/// let mut wrapper: Box<RwApiWrapperOwned<dyn Counter>> = Box::new(RwApiWrapperOwned(Concrete(1)));
+/// assert_eq!(increment(&mut *wrapper), 2);
+///
+/// let lock: Box<RwLock<[u8]>> = Box::new(RwLock::new([1, 2, 3]));
+/// assert_eq!(zero_first(&*lock), 3);
+/// assert_eq!(*lock.read(), [0, 2, 3]);
+/// ```
 pub trait RwApi: ReadApi + WriteApi + UpgradableReadApi + DowngradableWriteApi {}
 
+/// Provides the read and write part of the [`RwApi`] interface,
+/// which is natively supported by most backends, such as [`std::sync::RwLock`].
+///
+/// Wrap an implementor into [`Emulated`] to get the full [`RwApi`] interface.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::RwLock;
+/// use read_write_api::{BasicRwApi, RwApiWrapperOwned};
+///
+/// fn increment(mut x: impl BasicRwApi<Target=u64>) -> u64 {
+///     *x.write() += 1;
+///     *x.read()
+/// }
+///
+/// let lock = RwLock::new(1);
+/// assert_eq!(increment(&lock), 2);
+/// assert_eq!(increment(RwApiWrapperOwned(3)), 4);
+/// assert_eq!(increment(&mut parking_lot::RwLock::new(5)), 6);
+/// ```
+pub trait BasicRwApi: ReadApi + WriteApi {}
+
 /// Provides a constant part of the [`RwApi`] interface.
 ///
 /// # Example
@@ -135,7 +265,7 @@ pub trait RwApi: ReadApi + WriteApi + UpgradableReadApi + DowngradableWriteApi {
 pub trait ReadApi: GuardedTarget
 {
     /// [`Self::read`] return type.
-    type ReadGuard<'a>: Deref<Target=Self::Target>
+    type ReadGuard<'a>: MapReadGuard<'a, Target=Self::Target>
         where Self: 'a;
 
     /// Generalizes [`RwLock::read`](parking_lot::RwLock::read).
@@ -200,13 +330,56 @@ pub trait ReadApi: GuardedTarget
 pub trait WriteApi: GuardedTarget
 {
     /// [`Self::write`] return type.
-    type WriteGuard<'a>: DerefMut<Target=Self::Target>
+    type WriteGuard<'a>: MapWriteGuard<'a, Target=Self::Target>
         where Self: 'a;
 
     /// Generalizes [`RwLock::write`](parking_lot::RwLock::write).
     fn write(&mut self) -> Self::WriteGuard<'_>;
 }
 
+/// Provides an interface for re-entrant read acquisition.
+///
+/// Unlike [`ReadApi::read`], [`Self::read_recursive`] succeeds
+/// even if a writer is waiting, so it doesn't deadlock
+/// when the current thread already holds a read guard.
+///
+/// Backends that can't support it, such as [`std::sync::RwLock`],
+/// don't implement this trait, so the need for re-entrant reads
+/// is checked at compile time.
+/// The same goes for [`RefCell`](std::cell::RefCell),
+/// which can't wait for a conflicting borrow to be released.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{RecursiveReadApi, RwApiWrapperOwned};
+///
+/// fn sum_twice(x: &impl RecursiveReadApi<Target=Vec<u64>>) -> u64 {
+///     let outer = x.read_recursive();
+///     let inner = x.try_read_recursive().unwrap();
+///     outer.iter().sum::<u64>() + inner.iter().sum::<u64>()
+/// }
+///
+/// assert_eq!(sum_twice(&RwLock::new(vec![1, 2])), 6);
+/// assert_eq!(sum_twice(&&RwLock::new(vec![1, 2])), 6);
+/// assert_eq!(sum_twice(&RwApiWrapperOwned(vec![1, 2])), 6);
+/// ```
+pub trait RecursiveReadApi: ReadApi
+{
+    /// Generalizes [`RwLock::read_recursive`](parking_lot::RwLock::read_recursive).
+    fn read_recursive(&self) -> Self::ReadGuard<'_>;
+
+    /// Generalizes [`RwLock::try_read_recursive`](parking_lot::RwLock::try_read_recursive).
+    fn try_read_recursive(&self) -> Result<Self::ReadGuard<'_>, TryLockError>;
+
+    /// Generalizes [`RwLock::try_read_recursive_for`](parking_lot::RwLock::try_read_recursive_for).
+    fn try_read_recursive_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError>;
+
+    /// Generalizes [`RwLock::try_read_recursive_until`](parking_lot::RwLock::try_read_recursive_until).
+    fn try_read_recursive_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError>;
+}
+
 /// Provides a constant (but upgradable) part of the [`RwApi`] interface.
 ///
 /// # Example
@@ -238,6 +411,544 @@ pub trait DowngradableWriteApi: GuardedTarget
     fn downgradable_write(&mut self) -> Self::DowngradableWriteGuard<'_>;
 }
 
+/// Provides an [`RwApi`] analogue for [`Arc`]-held locks,
+/// which guards own a clone of the [`Arc`] instead of borrowing the lock.
+///
+/// Such guards are `'static` as long as the guarded data is,
+/// so they can be stored in structs or returned from functions
+/// that own the handle.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     DowngradableWriteGuard,
+///     OwnedDowngradableWriteApi,
+///     OwnedReadApi,
+///     OwnedRwApi,
+///     OwnedWriteApi,
+///     UpgradableReadGuard,
+/// };
+///
+/// struct Cursor<L: OwnedRwApi<Target=Vec<u64>>> {
+///     guard: L::OwnedUpgradableReadGuard,
+/// }
+///
+/// fn cursor<L: OwnedRwApi<Target=Vec<u64>>>(data: Vec<u64>) -> Cursor<L>
+///     where
+///         L: From<Vec<u64>>
+/// {
+///     let lock = Arc::new(L::from(data));
+///     Cursor { guard: lock.upgradable_read_owned() }
+/// }
+///
+/// let cursor: Cursor<RwLock<_>> = cursor(vec![1, 2]);
+/// let mut guard = cursor.guard.upgrade_to_downgradable();
+/// guard.push(3);
+/// assert_eq!(*guard.downgrade(), [1, 2, 3]);
+///
+/// let lock = Arc::new(RwLock::new(1));
+/// *lock.write_owned() = 5;
+/// assert_eq!(*lock.read_owned(), 5);
+/// assert_eq!(*lock.downgradable_write_owned().downgrade(), 5);
+/// ```
+pub trait OwnedRwApi: OwnedReadApi
++ OwnedWriteApi
++ OwnedUpgradableReadApi
++ OwnedDowngradableWriteApi
+{}
+
+/// Provides a constant part of the [`OwnedRwApi`] interface.
+///
+/// # Example
+///
+/// See the [`OwnedRwApi`] docs for usage examples.
+pub trait OwnedReadApi: GuardedTarget
+{
+    /// [`Self::read_owned`] return type.
+    type OwnedReadGuard: Deref<Target=Self::Target>;
+
+    /// Generalizes [`RwLock::read_arc`](parking_lot::RwLock::read_arc).
+    fn read_owned(self: &Arc<Self>) -> Self::OwnedReadGuard;
+}
+
+/// Provides a mutable part of the [`OwnedRwApi`] interface.
+///
+/// # Example
+///
+/// See the [`OwnedRwApi`] docs for usage examples.
+pub trait OwnedWriteApi: GuardedTarget
+{
+    /// [`Self::write_owned`] return type.
+    type OwnedWriteGuard: DerefMut<Target=Self::Target>;
+
+    /// Generalizes [`RwLock::write_arc`](parking_lot::RwLock::write_arc).
+    fn write_owned(self: &Arc<Self>) -> Self::OwnedWriteGuard;
+}
+
+/// Provides a constant (but upgradable) part of the [`OwnedRwApi`] interface.
+///
+/// # Example
+///
+/// See the [`OwnedRwApi`] docs for usage examples.
+pub trait OwnedUpgradableReadApi: GuardedTarget
+{
+    /// [`Self::upgradable_read_owned`] return type.
+    type OwnedUpgradableReadGuard: UpgradableReadGuard<Target=Self::Target>;
+
+    /// Generalizes
+    /// [`RwLock::upgradable_read_arc`](parking_lot::RwLock::upgradable_read_arc).
+    fn upgradable_read_owned(self: &Arc<Self>) -> Self::OwnedUpgradableReadGuard;
+}
+
+/// Provides a mutable (but downgradable) part of the [`OwnedRwApi`] interface.
+///
+/// # Example
+///
+/// See the [`OwnedRwApi`] docs for usage examples.
+pub trait OwnedDowngradableWriteApi: GuardedTarget
+{
+    /// [`Self::downgradable_write_owned`] return type.
+    type OwnedDowngradableWriteGuard: DowngradableWriteGuard<Target=Self::Target>;
+
+    /// [`OwnedWriteApi::write_owned`] analogue, which return type can be downgraded.
+    fn downgradable_write_owned(self: &Arc<Self>) -> Self::OwnedDowngradableWriteGuard;
+}
+
+/// Provides an [`RwApi`] analogue with `&self` receivers
+/// for backends with interior mutability, such as [`RwLock`](parking_lot::RwLock)
+/// and their shared handles.
+///
+/// Unlike [`WriteApi::write`], [`SharedWriteApi::write_shared`] can't rely on the borrow checker
+/// to rule out deadlocks, so taking a second guard on the same thread may deadlock or panic.
+/// Code bounded by these traits states that it gives up the compile-time guarantee.
+///
+/// Wrappers, such as [`RwApiWrapper`] and [`RwApiWrapperOwned`],
+/// have no interior mutability and deliberately don't implement these traits.
+///
+/// # Example
+///
+/// ```rust
+/// use std::{rc::Rc, sync::Arc};
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     DowngradableWriteGuard,
+///     Emulated,
+///     ReadApi,
+///     SharedDowngradableWriteApi,
+///     SharedRwApi,
+///     SharedUpgradableReadApi,
+///     SharedWriteApi,
+///     UpgradableReadGuard,
+/// };
+///
+/// fn on_event(handle: &impl SharedRwApi<Target=u64>) -> u64 {
+///     {
+///         let guard = handle.upgradable_read_shared();
+///         if *guard == 1 {
+///             *guard.upgrade() = 2;
+///         }
+///     }
+///     *handle.write_shared() += 1;
+///     *handle.downgradable_write_shared().downgrade()
+/// }
+///
+/// assert_eq!(on_event(&RwLock::new(1)), 3);
+/// assert_eq!(on_event(&&RwLock::new(3)), 4);
+///
+/// let handle = Arc::new(RwLock::new(1));
+/// assert_eq!(on_event(&handle), 3);
+/// assert_eq!(*handle.read(), 3);
+///
+/// fn push(handle: &impl SharedWriteApi<Target=Vec<u64>>, value: u64) {
+///     handle.write_shared().push(value)
+/// }
+///
+/// let handle = Rc::new(std::cell::RefCell::new(vec![]));
+/// push(&handle, 1);
+/// push(&std::sync::RwLock::new(vec![]), 2);
+/// assert_eq!(*handle.read(), [1]);
+///
+/// assert_eq!(on_event(&Emulated(std::cell::RefCell::new(1))), 3);
+/// ```
+pub trait SharedRwApi: ReadApi
++ SharedWriteApi
++ SharedUpgradableReadApi
++ SharedDowngradableWriteApi
+{}
+
+/// Provides a mutable part of the [`SharedRwApi`] interface.
+///
+/// # Example
+///
+/// See the [`SharedRwApi`] docs for usage examples.
+pub trait SharedWriteApi: GuardedTarget
+{
+    /// [`Self::write_shared`] return type.
+    type SharedWriteGuard<'a>: MapWriteGuard<'a, Target=Self::Target>
+        where Self: 'a;
+
+    /// [`WriteApi::write`] analogue, which takes a shared reference.
+    fn write_shared(&self) -> Self::SharedWriteGuard<'_>;
+}
+
+/// Provides a constant (but upgradable) part of the [`SharedRwApi`] interface.
+///
+/// # Example
+///
+/// See the [`SharedRwApi`] docs for usage examples.
+pub trait SharedUpgradableReadApi: GuardedTarget
+{
+    /// [`Self::upgradable_read_shared`] return type.
+    type SharedUpgradableReadGuard<'a>: UpgradableReadGuard<Target=Self::Target>
+        where Self: 'a;
+
+    /// [`UpgradableReadApi::upgradable_read`] analogue, which takes a shared reference.
+    fn upgradable_read_shared(&self) -> Self::SharedUpgradableReadGuard<'_>;
+}
+
+/// Provides a mutable (but downgradable) part of the [`SharedRwApi`] interface.
+///
+/// # Example
+///
+/// See the [`SharedRwApi`] docs for usage examples.
+pub trait SharedDowngradableWriteApi: GuardedTarget
+{
+    /// [`Self::downgradable_write_shared`] return type.
+    type SharedDowngradableWriteGuard<'a>: DowngradableWriteGuard<Target=Self::Target>
+        where Self: 'a;
+
+    /// [`DowngradableWriteApi::downgradable_write`] analogue, which takes a shared reference.
+    fn downgradable_write_shared(&self) -> Self::SharedDowngradableWriteGuard<'_>;
+}
+
+/// Provides a generic way to construct an [`RwApi`] implementor from the data to guard.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     IntoInnerApi,
+///     NewApi,
+///     Poisonable,
+///     ReadApi,
+///     RwApi,
+///     RwApiWrapperOwned,
+///     WriteApi,
+/// };
+///
+/// struct Cache<L: RwApi<Target=HashMap<u64, String>>> {
+///     entries: L,
+/// }
+///
+/// impl<L> Cache<L>
+///     where
+///         L: RwApi<Target=HashMap<u64, String>> + NewApi + IntoInnerApi
+/// {
+///     fn new() -> Self {
+///         Self { entries: L::from_default() }
+///     }
+///
+///     fn insert(&mut self, key: u64, value: &str) {
+///         self.entries.write().insert(key, value.to_string());
+///     }
+///
+///     fn into_entries(self) -> HashMap<u64, String> {
+///         self.entries.into_inner()
+///     }
+/// }
+///
+/// let mut cache: Cache<RwLock<_>> = Cache::new();
+/// cache.insert(1, "one");
+/// assert_eq!(cache.into_entries()[&1], "one");
+///
+/// let mut cache: Cache<RwApiWrapperOwned<_>> = Cache::new();
+/// cache.insert(2, "two");
+/// assert_eq!(cache.into_entries()[&2], "two");
+///
+/// let lock: std::sync::RwLock<_> = NewApi::from_value(5);
+/// assert_eq!(*ReadApi::read(&lock), 5);
+/// assert_eq!(IntoInnerApi::into_inner(lock), 5);
+///
+/// let mut cache: Cache<Poisonable<RwLock<_>>> = Cache::new();
+/// cache.insert(3, "three");
+/// assert_eq!(cache.into_entries()[&3], "three");
+/// ```
+pub trait NewApi: GuardedTarget + Sized
+{
+    /// Generalizes [`RwLock::new`](parking_lot::RwLock::new).
+    fn from_value(value: Self::Target) -> Self;
+
+    /// Constructs an instance guarding the default value of [`GuardedTarget::Target`].
+    #[inline]
+    fn from_default() -> Self
+        where Self::Target: Default
+    {
+        Self::from_value(Default::default())
+    }
+}
+
+/// Provides a generic way to take the guarded data out of an [`RwApi`] implementor.
+///
+/// # Example
+///
+/// See the [`NewApi`] docs for usage examples.
+pub trait IntoInnerApi: GuardedTarget + Sized
+{
+    /// Generalizes [`RwLock::into_inner`](parking_lot::RwLock::into_inner).
+    fn into_inner(self) -> Self::Target;
+}
+
+/// Provides a non-blocking part of the [`ReadApi`] interface.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::RefCell;
+/// use parking_lot::RwLock;
+/// use read_write_api::{ReadApi, RwApiWrapperOwned, TryLockError, TryReadApi};
+///
+/// fn read_if_free(x: &impl TryReadApi<Target=u64>) -> Result<u64, TryLockError> {
+///     x.try_read().map(|guard| *guard)
+/// }
+///
+/// assert_eq!(read_if_free(&RwApiWrapperOwned(1)), Ok(1));
+///
+/// let lock = RwLock::new(2);
+/// assert_eq!(read_if_free(&&lock), Ok(2));
+/// let guard = lock.write();
+/// assert_eq!(read_if_free(&&lock), Err(TryLockError::WouldBlock));
+/// drop(guard);
+///
+/// let cell = RefCell::new(3);
+/// assert_eq!(read_if_free(&&cell), Ok(3));
+/// let guard = cell.borrow_mut();
+/// assert_eq!(read_if_free(&&cell), Err(TryLockError::AlreadyBorrowed));
+/// drop(guard);
+///
+/// let lock = std::sync::RwLock::new(4);
+/// let _ = std::panic::catch_unwind(|| {
+///     let _guard = lock.write().unwrap();
+///     panic!()
+/// });
+/// assert_eq!(read_if_free(&&lock), Err(TryLockError::Poisoned));
+/// assert_eq!(*ReadApi::read(&lock), 4);
+/// ```
+pub trait TryReadApi: ReadApi
+{
+    /// Generalizes [`RwLock::try_read`](parking_lot::RwLock::try_read).
+    fn try_read(&self) -> Result<Self::ReadGuard<'_>, TryLockError>;
+}
+
+/// Provides a non-blocking part of the [`WriteApi`] interface.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{RwApiWrapperOwned, TryWriteApi};
+///
+/// fn increment_if_free(mut x: impl TryWriteApi<Target=u64>) -> bool {
+///     if let Ok(mut guard) = x.try_write() {
+///         *guard += 1;
+///         true
+///     } else {
+///         false
+///     }
+/// }
+///
+/// assert!(increment_if_free(RwApiWrapperOwned(1)));
+/// assert!(increment_if_free(&mut RwApiWrapperOwned(1)));
+///
+/// let lock = RwLock::new(1);
+/// assert!(increment_if_free(&lock));
+/// let guard = lock.read();
+/// assert!(!increment_if_free(&lock));
+/// drop(guard);
+/// assert_eq!(*lock.read(), 2);
+/// ```
+pub trait TryWriteApi: WriteApi
+{
+    /// Generalizes [`RwLock::try_write`](parking_lot::RwLock::try_write).
+    fn try_write(&mut self) -> Result<Self::WriteGuard<'_>, TryLockError>;
+}
+
+/// Provides a non-blocking part of the [`UpgradableReadApi`] interface.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     RwApiWrapperOwned,
+///     TryLockError,
+///     TryUpgradableReadApi,
+///     UpgradableReadGuard,
+/// };
+///
+/// fn set_if_free(mut x: impl TryUpgradableReadApi<Target=u64>) -> Result<bool, TryLockError> {
+///     let guard = x.try_upgradable_read()?;
+///     if *guard == 1 {
+///         match guard.try_upgrade() {
+///             Ok(mut guard) => *guard = 2,
+///             Err(_guard) => return Err(TryLockError::WouldBlock),
+///         }
+///         Ok(true)
+///     } else {
+///         Ok(false)
+///     }
+/// }
+///
+/// assert_eq!(set_if_free(RwApiWrapperOwned(1)), Ok(true));
+/// assert_eq!(set_if_free(&mut RwApiWrapperOwned(3)), Ok(false));
+///
+/// let lock = RwLock::new(1);
+/// let reader = lock.read();
+/// assert_eq!(set_if_free(&lock), Err(TryLockError::WouldBlock));
+/// drop(reader);
+/// let upgradable = lock.upgradable_read();
+/// assert_eq!(set_if_free(&lock), Err(TryLockError::WouldBlock));
+/// drop(upgradable);
+/// assert_eq!(set_if_free(&lock), Ok(true));
+/// assert_eq!(*lock.read(), 2);
+/// ```
+pub trait TryUpgradableReadApi: UpgradableReadApi
+{
+    /// Generalizes
+    /// [`RwLock::try_upgradable_read`](parking_lot::RwLock::try_upgradable_read).
+    fn try_upgradable_read(&mut self) -> Result<Self::UpgradableReadGuard<'_>, TryLockError>;
+}
+
+/// Provides a deadline-bounded part of the [`ReadApi`] interface.
+///
+/// Backends that can't wait for a lock with a timeout don't implement it,
+/// so generic code requires timeout support by bounding on this trait.
+/// A failed attempt is reported as [`TryLockError::WouldBlock`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use parking_lot::RwLock;
+/// use read_write_api::{RwApiWrapperOwned, TimedReadApi, TryLockError};
+///
+/// fn read_within(x: &impl TimedReadApi<Target=u64>, timeout: Duration) -> Result<u64, TryLockError> {
+///     x.try_read_for(timeout).map(|guard| *guard)
+/// }
+///
+/// fn read_before(x: &impl TimedReadApi<Target=u64>, deadline: Instant) -> Result<u64, TryLockError> {
+///     x.try_read_until(deadline).map(|guard| *guard)
+/// }
+///
+/// let timeout = Duration::from_millis(10);
+///
+/// assert_eq!(read_within(&RwApiWrapperOwned(1), timeout), Ok(1));
+/// assert_eq!(read_before(&RwApiWrapperOwned(1), Instant::now() + timeout), Ok(1));
+///
+/// let lock = RwLock::new(2);
+/// assert_eq!(read_within(&&lock, timeout), Ok(2));
+/// let guard = lock.write();
+/// assert_eq!(read_within(&&lock, timeout), Err(TryLockError::WouldBlock));
+/// assert_eq!(read_before(&&lock, Instant::now() + timeout), Err(TryLockError::WouldBlock));
+/// drop(guard);
+/// assert_eq!(read_before(&&lock, Instant::now() + timeout), Ok(2));
+/// ```
+pub trait TimedReadApi: TryReadApi
+{
+    /// Generalizes [`RwLock::try_read_for`](parking_lot::RwLock::try_read_for).
+    fn try_read_for(&self, timeout: Duration) -> Result<Self::ReadGuard<'_>, TryLockError>;
+
+    /// Generalizes [`RwLock::try_read_until`](parking_lot::RwLock::try_read_until).
+    fn try_read_until(&self, deadline: Instant) -> Result<Self::ReadGuard<'_>, TryLockError>;
+}
+
+/// Provides a deadline-bounded part of the [`WriteApi`] interface.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use parking_lot::RwLock;
+/// use read_write_api::{RwApiWrapperOwned, TimedWriteApi, TryLockError};
+///
+/// fn set_within(mut x: impl TimedWriteApi<Target=u64>, value: u64) -> Result<(), TryLockError> {
+///     *x.try_write_for(Duration::from_millis(10))? = value;
+///     Ok(())
+/// }
+///
+/// assert_eq!(set_within(RwApiWrapperOwned(1), 2), Ok(()));
+/// assert_eq!(set_within(&mut RwApiWrapperOwned(1), 2), Ok(()));
+///
+/// let lock = RwLock::new(1);
+/// let guard = lock.read();
+/// assert_eq!(set_within(&lock, 2), Err(TryLockError::WouldBlock));
+/// drop(guard);
+/// assert_eq!(set_within(&lock, 2), Ok(()));
+/// assert_eq!(*lock.read(), 2);
+/// ```
+pub trait TimedWriteApi: TryWriteApi
+{
+    /// Generalizes [`RwLock::try_write_for`](parking_lot::RwLock::try_write_for).
+    fn try_write_for(&mut self, timeout: Duration) -> Result<Self::WriteGuard<'_>, TryLockError>;
+
+    /// Generalizes [`RwLock::try_write_until`](parking_lot::RwLock::try_write_until).
+    fn try_write_until(&mut self, deadline: Instant) -> Result<Self::WriteGuard<'_>, TryLockError>;
+}
+
+/// Provides a deadline-bounded part of the [`UpgradableReadApi`] interface.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     RwApiWrapperOwned,
+///     TimedUpgradableReadApi,
+///     TryLockError,
+///     UpgradableReadGuard,
+/// };
+///
+/// fn set_before(mut x: impl TimedUpgradableReadApi<Target=u64>, deadline: Instant) -> Result<(), TryLockError> {
+///     let guard = x.try_upgradable_read_until(deadline)?;
+///     if *guard == 1 {
+///         let mut guard = guard
+///             .try_upgrade_until(deadline)
+///             .map_err(|_guard| TryLockError::WouldBlock)?;
+///         *guard = 2
+///     }
+///     Ok(())
+/// }
+///
+/// let deadline = Instant::now() + Duration::from_millis(10);
+/// assert_eq!(set_before(RwApiWrapperOwned(1), deadline), Ok(()));
+///
+/// let lock = RwLock::new(1);
+/// let reader = lock.read();
+/// assert_eq!(set_before(&lock, deadline), Err(TryLockError::WouldBlock));
+/// drop(reader);
+///
+/// let deadline = Instant::now() + Duration::from_millis(10);
+/// assert_eq!(set_before(&lock, deadline), Ok(()));
+/// assert_eq!(*lock.read(), 2);
+/// ```
+pub trait TimedUpgradableReadApi: TryUpgradableReadApi
+{
+    /// Generalizes
+    /// [`RwLock::try_upgradable_read_for`](parking_lot::RwLock::try_upgradable_read_for).
+    fn try_upgradable_read_for(&mut self, timeout: Duration)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>;
+
+    /// Generalizes
+    /// [`RwLock::try_upgradable_read_until`](parking_lot::RwLock::try_upgradable_read_until).
+    fn try_upgradable_read_until(&mut self, deadline: Instant)
+        -> Result<Self::UpgradableReadGuard<'_>, TryLockError>;
+}
+
 /// Provides an interface for upgrading upgradable read guards.
 ///
 /// # Example
@@ -258,8 +969,187 @@ pub trait UpgradableReadGuard: Deref
     /// [`RwLockUpgradableReadGuard::upgrade`](parking_lot::RwLockUpgradableReadGuard::upgrade).
     fn upgrade(self) -> Self::UpgradeResult;
 
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::try_upgrade`](parking_lot::RwLockUpgradableReadGuard::try_upgrade).
+    ///
+    /// On failure the guard is handed back intact.
+    ///
+    /// The default implementation never upgrades and always hands the guard back,
+    /// so backends able to upgrade without blocking override it.
+    #[inline]
+    fn try_upgrade(self) -> Result<Self::UpgradeResult, Self>
+        where Self: Sized
+    {
+        Err(self)
+    }
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::try_upgrade_for`](parking_lot::RwLockUpgradableReadGuard::try_upgrade_for).
+    ///
+    /// On failure the guard is handed back intact.
+    ///
+    /// The default implementation doesn't wait and falls back to [`Self::try_upgrade`].
+    #[inline]
+    fn try_upgrade_for(self, _timeout: Duration) -> Result<Self::UpgradeResult, Self>
+        where Self: Sized
+    {
+        self.try_upgrade()
+    }
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::try_upgrade_until`](parking_lot::RwLockUpgradableReadGuard::try_upgrade_until).
+    ///
+    /// On failure the guard is handed back intact.
+    ///
+    /// The default implementation doesn't wait and falls back to [`Self::try_upgrade`].
+    #[inline]
+    fn try_upgrade_until(self, _deadline: Instant) -> Result<Self::UpgradeResult, Self>
+        where Self: Sized
+    {
+        self.try_upgrade()
+    }
+
     /// [`Self::upgrade`] analogue, which return type can be downgraded.
     fn upgrade_to_downgradable(self) -> Self::UpgradeToDowngradableResult;
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::downgrade`](parking_lot::RwLockUpgradableReadGuard::downgrade).
+    ///
+    /// The default implementation goes through [`Self::upgrade_to_downgradable`],
+    /// so it waits for the other readers, and backends able to downgrade directly override it.
+    #[inline]
+    fn downgrade(self) -> <Self::UpgradeToDowngradableResult as DowngradableWriteGuard>::DowngradeResult
+        where Self: Sized
+    {
+        self.upgrade_to_downgradable().downgrade()
+    }
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::with_upgraded`](parking_lot::RwLockUpgradableReadGuard::with_upgraded).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use parking_lot::RwLock;
+    /// use read_write_api::{RwApiWrapperOwned, UpgradableReadApi, UpgradableReadGuard};
+    ///
+    /// fn fill_if_empty(mut x: impl UpgradableReadApi<Target=Vec<u64>>) -> usize {
+    ///     let mut guard = x.upgradable_read();
+    ///     if guard.is_empty() {
+    ///         guard.with_upgraded(|cache| cache.extend([1, 2, 3]))
+    ///     }
+    ///     guard.len()
+    /// }
+    ///
+    /// fn try_fill(mut x: impl UpgradableReadApi<Target=Vec<u64>>) -> Option<usize> {
+    ///     let mut guard = x.upgradable_read();
+    ///     guard.try_with_upgraded(|cache| {
+    ///         cache.push(4);
+    ///         cache.len()
+    ///     })
+    /// }
+    ///
+    /// let lock = RwLock::new(vec![]);
+    /// assert_eq!(fill_if_empty(&lock), 3);
+    ///
+    /// let reader = lock.read();
+    /// assert_eq!(try_fill(&lock), None);
+    /// drop(reader);
+    /// assert_eq!(try_fill(&lock), Some(4));
+    ///
+    /// assert_eq!(fill_if_empty(&mut RwApiWrapperOwned(vec![])), 3);
+    /// assert_eq!(try_fill(&mut RwApiWrapperOwned(vec![])), Some(1));
+    /// ```
+    ///
+    /// The default implementation upgrades the guard with [`Self::upgrade_to_downgradable`]
+    /// for the duration of `f` and puts it back downgraded,
+    /// aborting the process if any of these two conversions panics.
+    #[inline]
+    fn with_upgraded<Ret, F>(&mut self, f: F) -> Ret
+        where
+            Self: Sized,
+            F: FnOnce(&mut Self::Target) -> Ret
+    {
+        let abort = AbortOnUnwind;
+        // SAFETY: `Upgraded::drop` writes the guard back, even when `f` panics,
+        // and the process is aborted if it can't.
+        let guard = unsafe { ptr::read(self) }.upgrade_to_downgradable();
+        let mut upgraded = Upgraded { slot: self, guard: ManuallyDrop::new(guard) };
+        mem::forget(abort);
+        f(&mut upgraded.guard)
+    }
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::try_with_upgraded`](parking_lot::RwLockUpgradableReadGuard::try_with_upgraded).
+    ///
+    /// Returns [`None`] without calling `f` if the guard couldn't be upgraded.
+    ///
+    /// The default implementation never upgrades and always returns [`None`],
+    /// so backends able to upgrade without blocking override it.
+    #[inline]
+    fn try_with_upgraded<Ret, F>(&mut self, _f: F) -> Option<Ret>
+        where F: FnOnce(&mut Self::Target) -> Ret
+    {
+        None
+    }
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::try_with_upgraded_for`](parking_lot::RwLockUpgradableReadGuard::try_with_upgraded_for).
+    ///
+    /// Returns [`None`] without calling `f` if the guard couldn't be upgraded.
+    ///
+    /// The default implementation doesn't wait and falls back to [`Self::try_with_upgraded`].
+    #[inline]
+    fn try_with_upgraded_for<Ret, F>(&mut self, _timeout: Duration, f: F) -> Option<Ret>
+        where F: FnOnce(&mut Self::Target) -> Ret
+    {
+        self.try_with_upgraded(f)
+    }
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::try_with_upgraded_until`](parking_lot::RwLockUpgradableReadGuard::try_with_upgraded_until).
+    ///
+    /// Returns [`None`] without calling `f` if the guard couldn't be upgraded.
+    ///
+    /// The default implementation doesn't wait and falls back to [`Self::try_with_upgraded`].
+    #[inline]
+    fn try_with_upgraded_until<Ret, F>(&mut self, _deadline: Instant, f: F) -> Option<Ret>
+        where F: FnOnce(&mut Self::Target) -> Ret
+    {
+        self.try_with_upgraded(f)
+    }
+}
+
+/// Write guard lent by the default [`UpgradableReadGuard::with_upgraded`],
+/// which puts it back into `slot` downgraded once dropped.
+struct Upgraded<'s, G: UpgradableReadGuard> {
+    slot: &'s mut G,
+    guard: ManuallyDrop<G::UpgradeToDowngradableResult>,
+}
+
+impl<G: UpgradableReadGuard> Drop for Upgraded<'_, G>
+{
+    #[inline]
+    fn drop(&mut self) {
+        let abort = AbortOnUnwind;
+        // SAFETY: `guard` isn't used after being taken, and `slot` was moved out of.
+        unsafe {
+            let guard = ManuallyDrop::take(&mut self.guard);
+            ptr::write(self.slot, guard.downgrade_to_upgradable())
+        }
+        mem::forget(abort)
+    }
+}
+
+/// Aborts the process if dropped, which only happens while unwinding.
+struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind
+{
+    #[inline]
+    fn drop(&mut self) {
+        process::abort()
+    }
 }
 
 /// Provides an interface for downgrading downgradable write guards.
@@ -285,6 +1175,200 @@ pub trait DowngradableWriteGuard: DerefMut
     fn downgrade_to_upgradable(self) -> Self::DowngradeToUpgradableResult;
 }
 
+/// Provides an interface for projecting read guards onto a part of the guarded data.
+///
+/// Like in `parking_lot`, the methods are associated functions,
+/// so that they don't shadow the methods of the guarded data.
+///
+/// # Example
+///
+/// ```rust
+/// use std::{cell::RefCell, ops::Deref};
+/// use parking_lot::RwLock;
+/// use read_write_api::{MapReadGuard, ReadApi, RwApiWrapperOwned};
+///
+/// struct User {
+///     name: String,
+///     nickname: Option<String>,
+/// }
+///
+/// fn name(x: &impl ReadApi<Target=User>) -> impl Deref<Target=str> + '_ {
+///     MapReadGuard::map(x.read(), |user| user.name.as_str())
+/// }
+///
+/// fn nickname(x: &impl ReadApi<Target=User>) -> Option<impl Deref<Target=str> + '_> {
+///     MapReadGuard::try_map(x.read(), |user| user.nickname.as_deref()).ok()
+/// }
+///
+/// let user = || User { name: "Andrew".into(), nickname: None };
+///
+/// assert_eq!(&*name(&RwApiWrapperOwned(user())), "Andrew");
+/// assert_eq!(&*name(&RwLock::new(user())), "Andrew");
+/// assert_eq!(&*name(&&RwLock::new(user())), "Andrew");
+/// assert_eq!(&*name(&RefCell::new(user())), "Andrew");
+/// assert_eq!(&*name(&std::sync::RwLock::new(user())), "Andrew");
+///
+/// assert!(nickname(&RwLock::new(user())).is_none());
+/// ```
+pub trait MapReadGuard<'a>: Deref
+{
+    /// [`Self::map`] return type.
+    type Mapped<U: ?Sized + 'a>: MapReadGuard<'a, Target=U>;
+
+    /// Generalizes [`RwLockReadGuard::map`](parking_lot::RwLockReadGuard::map).
+    fn map<U, F>(s: Self, f: F) -> Self::Mapped<U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&Self::Target) -> &U;
+
+    /// Generalizes [`RwLockReadGuard::try_map`](parking_lot::RwLockReadGuard::try_map).
+    ///
+    /// On failure the guard is handed back intact.
+    fn try_map<U, F>(s: Self, f: F) -> Result<Self::Mapped<U>, Self>
+        where
+            Self: Sized,
+            U: ?Sized + 'a,
+            F: FnOnce(&Self::Target) -> Option<&U>;
+}
+
+/// Provides an interface for projecting write guards onto a part of the guarded data.
+///
+/// Like in `parking_lot`, the methods are associated functions,
+/// so that they don't shadow the methods of the guarded data.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::DerefMut;
+/// use parking_lot::RwLock;
+/// use read_write_api::{MapWriteGuard, RwApiWrapperOwned, WriteApi};
+///
+/// struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// fn age(x: &mut impl WriteApi<Target=User>) -> impl DerefMut<Target=u8> + '_ {
+///     MapWriteGuard::map(x.write(), |user| &mut user.age)
+/// }
+///
+/// let user = || User { name: "Andrew".into(), age: 30 };
+///
+/// let mut wrapper = RwApiWrapperOwned(user());
+/// *age(&mut wrapper) += 1;
+/// assert_eq!(wrapper.0.age, 31);
+///
+/// let lock = RwLock::new(user());
+/// *age(&mut &lock) += 1;
+/// assert_eq!(lock.read().age, 31);
+///
+/// let lock = std::sync::RwLock::new(user());
+/// *age(&mut &lock) += 1;
+/// assert_eq!(lock.read().unwrap().age, 31);
+/// ```
+pub trait MapWriteGuard<'a>: DerefMut
+{
+    /// [`Self::map`] return type.
+    type Mapped<U: ?Sized + 'a>: MapWriteGuard<'a, Target=U>;
+
+    /// Generalizes [`RwLockWriteGuard::map`](parking_lot::RwLockWriteGuard::map).
+    fn map<U, F>(s: Self, f: F) -> Self::Mapped<U>
+        where
+            U: ?Sized + 'a,
+            F: FnOnce(&mut Self::Target) -> &mut U;
+
+    /// Generalizes [`RwLockWriteGuard::try_map`](parking_lot::RwLockWriteGuard::try_map).
+    ///
+    /// On failure the guard is handed back intact.
+    fn try_map<U, F>(s: Self, f: F) -> Result<Self::Mapped<U>, Self>
+        where
+            Self: Sized,
+            U: ?Sized + 'a,
+            F: FnOnce(&mut Self::Target) -> Option<&mut U>;
+}
+
+/// Provides an interface for temporarily releasing a held guard.
+///
+/// Like in `parking_lot`, the methods are associated functions,
+/// so that they don't shadow the methods of the guarded data.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{ReadApi, RwApiWrapperOwned, UnlockableGuard};
+///
+/// fn sum(mut guard: impl UnlockableGuard<Target=Vec<u64>>) -> u64 {
+///     let mut sum = 0;
+///     for i in 0..guard.len() {
+///         sum += guard[i];
+///         if i % 2 == 1 {
+///             UnlockableGuard::bump(&mut guard);
+///         }
+///     }
+///     sum + UnlockableGuard::unlocked(&mut guard, || 1)
+/// }
+///
+/// assert_eq!(sum(RwLock::new(vec![1, 2, 3]).read()), 7);
+/// assert_eq!(sum(RwApiWrapperOwned(vec![1, 2, 3]).read()), 7);
+/// ```
+pub trait UnlockableGuard: Deref
+{
+    /// Generalizes [`RwLockReadGuard::unlocked`](parking_lot::RwLockReadGuard::unlocked).
+    ///
+    /// Guards that don't hold any lock just call `f`.
+    fn unlocked<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U;
+
+    /// Generalizes [`RwLockReadGuard::bump`](parking_lot::RwLockReadGuard::bump).
+    ///
+    /// Guards that don't hold any lock do nothing.
+    fn bump(s: &mut Self);
+}
+
+/// Provides an interface for releasing a held guard fairly,
+/// handing the lock over to the waiting threads in FIFO order.
+///
+/// Like in `parking_lot`, the methods are associated functions,
+/// so that they don't shadow the methods of the guarded data.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{FairUnlockGuard, RwApiWrapperOwned, WriteApi};
+///
+/// fn push<'a, T>(x: &'a mut T, value: u64) -> usize
+///     where
+///         T: WriteApi<Target=Vec<u64>>,
+///         T::WriteGuard<'a>: FairUnlockGuard
+/// {
+///     let mut guard = x.write();
+///     guard.push(value);
+///     let len = FairUnlockGuard::unlocked_fair(&mut guard, || 1);
+///     let len = len + guard.len();
+///     FairUnlockGuard::unlock_fair(guard);
+///     len
+/// }
+///
+/// let lock = RwLock::new(vec![]);
+/// assert_eq!(push(&mut &lock, 1), 2);
+/// assert_eq!(push(&mut RwApiWrapperOwned(vec![1]), 2), 3);
+/// ```
+pub trait FairUnlockGuard: Deref + Sized
+{
+    /// Generalizes [`RwLockReadGuard::unlock_fair`](parking_lot::RwLockReadGuard::unlock_fair).
+    ///
+    /// Guards that don't hold any lock are just dropped.
+    fn unlock_fair(s: Self);
+
+    /// Generalizes [`RwLockReadGuard::unlocked_fair`](parking_lot::RwLockReadGuard::unlocked_fair).
+    ///
+    /// Guards that don't hold any lock just call `f`.
+    fn unlocked_fair<U, F>(s: &mut Self, f: F) -> U
+        where F: FnOnce() -> U;
+}
+
 /// Provides a single dereferencing target type for
 /// the [`ReadApi`], [`WriteApi`] and [`RwApi`] traits.
 ///
@@ -353,7 +1437,7 @@ pub trait DowngradableWriteGuard: DerefMut
 pub trait GuardedTarget
 {
     /// Dereferencing target of the read and write guards.
-    type Target;
+    type Target: ?Sized;
 }
 
 #[cfg(doctest)]
@@ -0,0 +1,241 @@
+use {
+    crate::{
+        DowngradedGuard,
+        MapReadGuard,
+        MapWriteGuard,
+        UpgradableReadApi,
+        UpgradableReadGuard,
+        WriteApi,
+    },
+    std::{
+        fmt,
+        ops::{Deref, DerefMut},
+    },
+};
+
+/// Provides a unified interface over key-value collections
+/// used by the [`MapUpgradableReadApiExt`] and [`MapWriteApiExt`] extension traits.
+///
+/// Implemented for [`HashMap`](std::collections::HashMap),
+/// [`BTreeMap`](std::collections::BTreeMap)
+/// and, with the `indexmap` feature, for `IndexMap`.
+pub trait MapTarget
+{
+    /// Key type.
+    type Key;
+
+    /// Value type.
+    type Value;
+
+    /// Returns a reference to the value corresponding to the `key`.
+    fn get(&self, key: &Self::Key) -> Option<&Self::Value>;
+
+    /// Returns a mutable reference to the value corresponding to the `key`.
+    fn get_mut(&mut self, key: &Self::Key) -> Option<&mut Self::Value>;
+
+    /// Removes the `key` from the map, returning its value.
+    ///
+    /// Preserves the order of the remaining entries of ordered maps.
+    fn remove(&mut self, key: &Self::Key) -> Option<Self::Value>;
+
+    /// Inserts the `value` under the `key`, returning a mutable reference to it.
+    fn insert_and_get_mut(&mut self, key: Self::Key, value: Self::Value) -> &mut Self::Value;
+
+    /// Returns a mutable reference to the value corresponding to the `key`,
+    /// inserting the value returned by `f` if it's missing.
+    fn get_or_insert_with<F>(&mut self, key: Self::Key, f: F) -> &mut Self::Value
+        where F: FnOnce() -> Self::Value;
+}
+
+/// Provides map-aware operations over [`UpgradableReadApi`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::{BTreeMap, HashMap};
+/// use parking_lot::RwLock;
+/// use read_write_api::{GetOrInsertGuard, MapUpgradableReadApiExt, RwApiWrapperOwned};
+///
+/// let lock = RwLock::new(HashMap::new());
+/// let mut lock_ref = &lock;
+/// assert!(matches!(lock_ref.get_or_insert_with("a", || 1), GetOrInsertGuard::Inserted(_)));
+/// let guard = lock_ref.get_or_insert_with("a", || 2);
+/// assert!(matches!(guard, GetOrInsertGuard::Found(_)));
+/// assert_eq!(*guard, 1);
+/// drop(guard);
+///
+/// let mut wrapper = RwApiWrapperOwned(BTreeMap::new());
+/// assert_eq!(*wrapper.get_or_insert_with(1, String::new), "");
+/// ```
+///
+/// With the `indexmap` feature:
+///
+/// ```rust
+/// # #[cfg(feature = "indexmap")]
+/// # {
+/// use indexmap::IndexMap;
+/// use parking_lot::RwLock;
+/// use read_write_api::MapUpgradableReadApiExt;
+///
+/// let lock = RwLock::new(IndexMap::from([("a", 1)]));
+/// assert_eq!(*(&lock).get_or_insert_with("b", || 2), 2);
+/// assert_eq!(*(&lock).get_or_insert_with("a", || 3), 1);
+/// assert_eq!(lock.read().keys().copied().collect::<Vec<_>>(), ["a", "b"]);
+/// # }
+/// ```
+pub trait MapUpgradableReadApiExt: UpgradableReadApi
+{
+    /// Returns a guard projected onto the value corresponding to the `key`,
+    /// inserting the value returned by `f` if it's missing.
+    ///
+    /// The lock is upgraded only if the value is missing,
+    /// in which case the returned guard keeps holding it exclusively.
+    #[allow(clippy::type_complexity)]
+    fn get_or_insert_with<'a, M, F>(&'a mut self, key: M::Key, f: F) -> GetOrInsertGuard<
+        <DowngradedGuard<'a, Self> as MapReadGuard<'a>>::Mapped<M::Value>,
+        <<Self::UpgradableReadGuard<'a> as UpgradableReadGuard>::UpgradeResult as MapWriteGuard<'a>>::Mapped<M::Value>
+    >
+        where
+            Self: UpgradableReadApi<Target=M>,
+            M: MapTarget + 'a,
+            DowngradedGuard<'a, Self>: MapReadGuard<'a, Target=M>,
+            <Self::UpgradableReadGuard<'a> as UpgradableReadGuard>::UpgradeResult: MapWriteGuard<'a, Target=M>,
+            F: FnOnce() -> M::Value;
+}
+
+/// Guard projected onto a value of a [`MapTarget`],
+/// which was either found or inserted into it.
+///
+/// Returned by [`MapUpgradableReadApiExt::get_or_insert_with`].
+pub enum GetOrInsertGuard<R, W> {
+    /// Read guard onto the value which was already there.
+    Found(R),
+    /// Write guard onto the freshly inserted value.
+    Inserted(W),
+}
+
+impl<R: Deref, W: Deref<Target=R::Target>> Deref for GetOrInsertGuard<R, W>
+{
+    type Target = R::Target;
+
+    #[inline(always)]
+    fn deref(&self) -> &R::Target {
+        match self {
+            Self::Found(guard) => guard,
+            Self::Inserted(guard) => guard,
+        }
+    }
+}
+
+impl<R: Deref, W: Deref<Target=R::Target>> fmt::Debug for GetOrInsertGuard<R, W>
+    where R::Target: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// Provides map-aware operations over [`WriteApi`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use parking_lot::RwLock;
+/// use read_write_api::{MapWriteApiExt, RwApiWrapperOwned};
+///
+/// let lock = RwLock::new(HashMap::from([("a", 1)]));
+/// let mut lock_ref = &lock;
+///
+/// let mut entry = lock_ref.entry_locked("a");
+/// *entry.get_mut().unwrap() += 1;
+/// assert_eq!(entry.remove(), Some(2));
+/// assert_eq!(*entry.or_insert_with(|| 5), 5);
+///
+/// let mut wrapper = RwApiWrapperOwned(HashMap::new());
+/// *wrapper.entry_locked("b").insert(1) += 1;
+/// assert_eq!(wrapper.0["b"], 2);
+/// ```
+pub trait MapWriteApiExt: WriteApi
+{
+    /// Returns a write guard projected onto the entry corresponding to the `key`.
+    fn entry_locked<M>(&mut self, key: M::Key) -> EntryGuard<Self::WriteGuard<'_>, M::Key>
+        where
+            Self: WriteApi<Target=M>,
+            M: MapTarget;
+}
+
+/// Write guard projected onto a single entry of a [`MapTarget`].
+///
+/// Returned by [`MapWriteApiExt::entry_locked`].
+pub struct EntryGuard<G, K> {
+    guard: G,
+    key: K,
+}
+
+impl<G, K> EntryGuard<G, K>
+    where
+        G: DerefMut,
+        G::Target: MapTarget<Key=K>
+{
+    /// Creates an entry guard over the `key` of the map guarded by `guard`.
+    #[inline]
+    pub fn new(guard: G, key: K) -> Self {
+        Self { guard, key }
+    }
+
+    /// Returns the key of the entry.
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns a reference to the value of the entry, if any.
+    #[inline]
+    pub fn get(&self) -> Option<&<G::Target as MapTarget>::Value> {
+        self.guard.get(&self.key)
+    }
+
+    /// Returns a mutable reference to the value of the entry, if any.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut <G::Target as MapTarget>::Value> {
+        self.guard.get_mut(&self.key)
+    }
+
+    /// Removes the entry from the map, returning its value.
+    #[inline]
+    pub fn remove(&mut self) -> Option<<G::Target as MapTarget>::Value> {
+        self.guard.remove(&self.key)
+    }
+
+    /// Inserts the `value` into the entry,
+    /// returning the guard projected onto it.
+    #[inline]
+    pub fn insert<'a>(self, value: <G::Target as MapTarget>::Value) -> G::Mapped<<G::Target as MapTarget>::Value>
+        where
+            G: MapWriteGuard<'a>,
+            <G::Target as MapTarget>::Value: 'a
+    {
+        let key = self.key;
+        MapWriteGuard::map(self.guard, |map| map.insert_and_get_mut(key, value))
+    }
+
+    /// Inserts the value returned by `f` into the entry if it's vacant,
+    /// returning the guard projected onto the value.
+    #[inline]
+    pub fn or_insert_with<'a, F>(self, f: F) -> G::Mapped<<G::Target as MapTarget>::Value>
+        where
+            G: MapWriteGuard<'a>,
+            <G::Target as MapTarget>::Value: 'a,
+            F: FnOnce() -> <G::Target as MapTarget>::Value
+    {
+        let key = self.key;
+        MapWriteGuard::map(self.guard, |map| map.get_or_insert_with(key, f))
+    }
+
+    /// Returns the guard of the whole map.
+    #[inline]
+    pub fn into_guard(self) -> G {
+        self.guard
+    }
+}
\ No newline at end of file
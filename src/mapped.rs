@@ -0,0 +1,200 @@
+use {
+    crate::{MapReadGuard, MapWriteGuard},
+    std::{
+        fmt,
+        marker::PhantomData,
+        ops::{Deref, DerefMut},
+        ptr::NonNull,
+    },
+};
+
+/// Read guard `G` projected onto a part of its data.
+///
+/// Returned by [`MapReadGuard::map`] for backends
+/// that can't project their guards natively, such as [`std::sync::RwLock`].
+pub struct MappedReadGuard<G, U: ?Sized> {
+    data: NonNull<U>,
+    _guard: G,
+}
+
+/// Write guard `G` projected onto a part of its data.
+///
+/// Returned by [`MapWriteGuard::map`] for backends
+/// that can't project their guards natively, such as [`std::sync::RwLock`].
+///
+/// Like `&mut U`, it's invariant in `U`:
+///
+/// ```compile_fail
+/// use read_write_api::MappedWriteGuard;
+///
+/// fn shorten<'s, G>(guard: MappedWriteGuard<G, &'static str>) -> MappedWriteGuard<G, &'s str> {
+///     guard
+/// }
+/// ```
+pub struct MappedWriteGuard<G, U: ?Sized> {
+    data: NonNull<U>,
+    _guard: G,
+    // Keeps `U` invariant like in `&mut U`.
+    _variance: PhantomData<*mut U>,
+}
+
+impl<G: Deref, U: ?Sized> MappedReadGuard<G, U>
+{
+    /// Projects `guard` onto the part of its data returned by `f`.
+    ///
+    /// # Safety
+    ///
+    /// `G` must keep its data at the same address after being moved
+    /// and keep it accessible until dropped.
+    #[inline]
+    pub unsafe fn new<F>(guard: G, f: F) -> Self
+        where F: FnOnce(&G::Target) -> &U
+    {
+        Self { data: NonNull::from(f(&guard)), _guard: guard }
+    }
+
+    /// Fallible version of [`Self::new`], handing `guard` back if `f` returns `None`.
+    ///
+    /// # Safety
+    ///
+    /// `G` must keep its data at the same address after being moved
+    /// and keep it accessible until dropped.
+    #[inline]
+    pub unsafe fn try_new<F>(guard: G, f: F) -> Result<Self, G>
+        where F: FnOnce(&G::Target) -> Option<&U>
+    {
+        match f(&guard).map(NonNull::from) {
+            Some(data) => Ok(Self { data, _guard: guard }),
+            None => Err(guard)
+        }
+    }
+}
+
+impl<G: DerefMut, U: ?Sized> MappedWriteGuard<G, U>
+{
+    /// Projects `guard` onto the part of its data returned by `f`.
+    ///
+    /// # Safety
+    ///
+    /// `G` must keep its data at the same address after being moved
+    /// and keep it accessible until dropped.
+    #[inline]
+    pub unsafe fn new<F>(mut guard: G, f: F) -> Self
+        where F: FnOnce(&mut G::Target) -> &mut U
+    {
+        Self { data: NonNull::from(f(&mut guard)), _guard: guard, _variance: PhantomData }
+    }
+
+    /// Fallible version of [`Self::new`], handing `guard` back if `f` returns `None`.
+    ///
+    /// # Safety
+    ///
+    /// `G` must keep its data at the same address after being moved
+    /// and keep it accessible until dropped.
+    #[inline]
+    pub unsafe fn try_new<F>(mut guard: G, f: F) -> Result<Self, G>
+        where F: FnOnce(&mut G::Target) -> Option<&mut U>
+    {
+        match f(&mut guard).map(NonNull::from) {
+            Some(data) => Ok(Self { data, _guard: guard, _variance: PhantomData }),
+            None => Err(guard)
+        }
+    }
+}
+
+impl<G, U: ?Sized> Deref for MappedReadGuard<G, U>
+{
+    type Target = U;
+
+    #[inline(always)]
+    fn deref(&self) -> &U {
+        // SAFETY: the data is borrowed from `_guard`, which is alive as long as `self`.
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<G, U: ?Sized> Deref for MappedWriteGuard<G, U>
+{
+    type Target = U;
+
+    #[inline(always)]
+    fn deref(&self) -> &U {
+        // SAFETY: the data is mutably borrowed from `_guard`, which is alive as long as `self`.
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<G, U: ?Sized> DerefMut for MappedWriteGuard<G, U>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut U {
+        // SAFETY: the data is mutably borrowed from `_guard`, which is alive as long as `self`.
+        unsafe { self.data.as_mut() }
+    }
+}
+
+impl<'a, G, U: ?Sized> MapReadGuard<'a> for MappedReadGuard<G, U>
+{
+    type Mapped<V: ?Sized + 'a> = MappedReadGuard<G, V>;
+
+    #[inline]
+    fn map<V, F>(s: Self, f: F) -> Self::Mapped<V>
+        where
+            V: ?Sized + 'a,
+            F: FnOnce(&U) -> &V
+    {
+        MappedReadGuard { data: NonNull::from(f(&s)), _guard: s._guard }
+    }
+
+    #[inline]
+    fn try_map<V, F>(s: Self, f: F) -> Result<Self::Mapped<V>, Self>
+        where
+            V: ?Sized + 'a,
+            F: FnOnce(&U) -> Option<&V>
+    {
+        match f(&s).map(NonNull::from) {
+            Some(data) => Ok(MappedReadGuard { data, _guard: s._guard }),
+            None => Err(s)
+        }
+    }
+}
+
+impl<'a, G, U: ?Sized> MapWriteGuard<'a> for MappedWriteGuard<G, U>
+{
+    type Mapped<V: ?Sized + 'a> = MappedWriteGuard<G, V>;
+
+    #[inline]
+    fn map<V, F>(mut s: Self, f: F) -> Self::Mapped<V>
+        where
+            V: ?Sized + 'a,
+            F: FnOnce(&mut U) -> &mut V
+    {
+        MappedWriteGuard { data: NonNull::from(f(&mut s)), _guard: s._guard, _variance: PhantomData }
+    }
+
+    #[inline]
+    fn try_map<V, F>(mut s: Self, f: F) -> Result<Self::Mapped<V>, Self>
+        where
+            V: ?Sized + 'a,
+            F: FnOnce(&mut U) -> Option<&mut V>
+    {
+        match f(&mut s).map(NonNull::from) {
+            Some(data) => Ok(MappedWriteGuard { data, _guard: s._guard, _variance: PhantomData }),
+            None => Err(s)
+        }
+    }
+}
+
+impl<G, U: ?Sized + fmt::Debug> fmt::Debug for MappedReadGuard<G, U>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<G, U: ?Sized + fmt::Debug> fmt::Debug for MappedWriteGuard<G, U>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
\ No newline at end of file
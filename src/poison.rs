@@ -0,0 +1,353 @@
+use {
+    crate::TryLockError,
+    std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+    },
+};
+
+/// Provides an interface to query the poisoning state of a lock.
+///
+/// Backends that never poison always report a healthy state.
+///
+/// # Example
+///
+/// See the [`Poisonable`] docs for usage examples.
+pub trait PoisonApi
+{
+    /// Generalizes [`RwLock::is_poisoned`](std::sync::RwLock::is_poisoned).
+    fn is_poisoned(&self) -> bool;
+}
+
+/// Provides an interface to reset the poisoning state of a lock.
+///
+/// Once [`Self::clear_poison`] returns, [`PoisonApi::is_poisoned`] returns `false`
+/// until the lock gets poisoned again.
+///
+/// Not implemented for [`std::sync::RwLock`], as its `clear_poison` requires Rust 1.77.
+/// Wrap it into a [`Poisonable`] to get the poisoning cleared.
+///
+/// # Example
+///
+/// See the [`Poisonable`] docs for usage examples.
+pub trait ClearPoisonApi: PoisonApi
+{
+    /// Generalizes [`RwLock::clear_poison`](std::sync::RwLock::clear_poison).
+    fn clear_poison(&self);
+}
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+/// Poisoning policy of a [`Poisonable`] lock.
+pub enum PoisonPolicy {
+    /// Poisoning is never reported, though the backend's own state is left untouched.
+    #[default]
+    Ignore,
+    /// Poisoning of the backend is reported as [`TryLockError::Poisoned`].
+    ///
+    /// Once the backend's poisoning is cleared through the adapter,
+    /// panics while holding the adapter's write-capable guards are tracked instead.
+    Propagate,
+    /// Like [`PoisonPolicy::Propagate`], but the lock also gets poisoned whenever
+    /// a write-capable guard is dropped during a panic,
+    /// even if the backend itself never poisons.
+    PoisonOnPanic,
+}
+
+/// Adapter applying a [`PoisonPolicy`] to any backend.
+///
+/// The fallible methods report poisoning as [`TryLockError::Poisoned`],
+/// while the infallible ones panic on it.
+///
+/// The methods taking the adapter by a shared reference check the poisoning
+/// right after the guard is acquired, so a panic of another thread
+/// that happens while waiting for the lock is never missed.
+/// The ones taking it by an exclusive reference can only check right before,
+/// which makes a difference only if the backend is also reachable around the adapter.
+///
+/// The fallible methods never block. Backends like `&std::sync::RwLock`,
+/// which release the guard when reporting their own poisoning,
+/// make them return [`TryLockError::WouldBlock`] instead,
+/// whenever the adapter doesn't report that poisoning itself.
+/// Share `&Poisonable<RwLock<T>>` rather than separate adapters over `&RwLock<T>`
+/// to have the [`PoisonPolicy::PoisonOnPanic`] state shared too.
+///
+/// # Example
+///
+/// ```rust
+/// use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     ClearPoisonApi,
+///     PoisonApi,
+///     Poisonable,
+///     PoisonPolicy,
+///     ReadApi,
+///     RwApiWrapperOwned,
+///     TryLockError,
+///     TryReadApi,
+///     TryWriteApi,
+///     WriteApi,
+/// };
+///
+/// fn fail_while_writing<T>(mut x: impl WriteApi<Target=T>) {
+///     let _ = catch_unwind(AssertUnwindSafe(move || {
+///         let _guard = x.write();
+///         panic!("failure")
+///     }));
+/// }
+///
+/// let lock = Poisonable::new(RwLock::new(1), PoisonPolicy::PoisonOnPanic);
+/// fail_while_writing(&lock);
+/// assert!(lock.is_poisoned());
+/// assert_eq!(TryReadApi::try_read(&&lock).err(), Some(TryLockError::Poisoned));
+/// lock.clear_poison();
+/// assert_eq!(*TryReadApi::try_read(&&lock).unwrap(), 1);
+///
+/// let mut wrapper = Poisonable::new(RwApiWrapperOwned(1), PoisonPolicy::PoisonOnPanic);
+/// fail_while_writing(&mut wrapper);
+/// assert!(wrapper.is_poisoned());
+///
+/// let lock = Poisonable::new(RwLock::new(1), PoisonPolicy::Propagate);
+/// fail_while_writing(&lock);
+/// assert!(!lock.is_poisoned());
+///
+/// let lock = Poisonable::new(std::sync::RwLock::new(1), PoisonPolicy::Propagate);
+/// fail_while_writing(&lock);
+/// assert!(lock.is_poisoned());
+/// lock.clear_poison();
+/// assert!(!lock.is_poisoned());
+/// fail_while_writing(&lock);
+/// assert!(lock.is_poisoned());
+///
+/// let lock = Poisonable::new(std::sync::RwLock::new(1), PoisonPolicy::Ignore);
+/// fail_while_writing(&lock);
+/// assert!(!lock.is_poisoned());
+/// assert!(lock.get_ref().is_poisoned());
+/// assert_eq!(*ReadApi::read(&&lock), 1);
+///
+/// let std_lock = std::sync::RwLock::new(1);
+/// fail_while_writing(&std_lock);
+/// let mut lock = Poisonable::new(&std_lock, PoisonPolicy::Ignore);
+/// let reader = std_lock.read().unwrap_err().into_inner();
+/// assert_eq!(TryWriteApi::try_write(&mut lock).err(), Some(TryLockError::WouldBlock));
+/// drop(reader);
+/// ```
+#[derive(Debug, Default)]
+pub struct Poisonable<L> {
+    pub(crate) lock: L,
+    pub(crate) policy: PoisonPolicy,
+    pub(crate) poisoned: AtomicBool,
+    backend_cleared: AtomicBool,
+}
+
+/// Guard of a [`Poisonable`] lock.
+///
+/// Poisons the lock if it's dropped during a panic while being able to write,
+/// as long as the [`PoisonPolicy`] tracks such panics.
+pub struct PoisonGuard<'a, G> {
+    pub(crate) flag: PoisonFlag<'a>,
+    pub(crate) guard: G,
+}
+
+pub(crate) struct PoisonFlag<'a> {
+    flag: Option<&'a AtomicBool>,
+    armed: bool,
+}
+
+impl<L> Poisonable<L>
+{
+    /// Wraps `lock` with the given poisoning `policy`.
+    #[inline]
+    pub fn new(lock: L, policy: PoisonPolicy) -> Self {
+        Self {
+            lock,
+            policy,
+            poisoned: AtomicBool::new(false),
+            backend_cleared: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the poisoning policy of the lock.
+    #[inline]
+    pub fn policy(&self) -> PoisonPolicy {
+        self.policy
+    }
+
+    /// Returns a reference to the wrapped backend.
+    #[inline]
+    pub fn get_ref(&self) -> &L {
+        &self.lock
+    }
+
+    /// Unwraps the backend.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lock
+    }
+}
+
+impl<L: PoisonApi> Poisonable<L>
+{
+    /// Wraps a `guard` freshly acquired from the backend according to the policy.
+    #[inline]
+    pub(crate) fn wrap<G>(&self, guard: G, armed: bool) -> Result<PoisonGuard<'_, G>, TryLockError> {
+        wrap_guard(&self.poisoned, self.policy, self.is_poisoned(), guard, armed)
+    }
+}
+
+impl<L: PoisonApi> PoisonApi for Poisonable<L>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        let backend_cleared = self.backend_cleared.load(Ordering::Acquire);
+        let backend_poisoned = !backend_cleared && self.lock.is_poisoned();
+        match self.policy {
+            PoisonPolicy::Ignore => false,
+            PoisonPolicy::Propagate => {
+                backend_poisoned || backend_cleared && self.poisoned.load(Ordering::Acquire)
+            }
+            PoisonPolicy::PoisonOnPanic => backend_poisoned || self.poisoned.load(Ordering::Acquire),
+        }
+    }
+}
+
+impl<L: PoisonApi> ClearPoisonApi for Poisonable<L>
+{
+    /// Clears the poisoning tracked by the adapter.
+    ///
+    /// The backend's own poisoning is left in place, but the adapter stops reporting it
+    /// and tracks the further panics by itself.
+    #[inline]
+    fn clear_poison(&self) {
+        if self.lock.is_poisoned() {
+            self.backend_cleared.store(true, Ordering::Release)
+        }
+        self.poisoned.store(false, Ordering::Release)
+    }
+}
+
+impl<L: PoisonApi> PoisonApi for &Poisonable<L>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        (**self).is_poisoned()
+    }
+}
+
+impl<L: PoisonApi> ClearPoisonApi for &Poisonable<L>
+{
+    #[inline]
+    fn clear_poison(&self) {
+        (**self).clear_poison()
+    }
+}
+
+impl<L: PoisonApi> PoisonApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn is_poisoned(&self) -> bool {
+        (**self).is_poisoned()
+    }
+}
+
+impl<L: PoisonApi> ClearPoisonApi for &mut Poisonable<L>
+{
+    #[inline]
+    fn clear_poison(&self) {
+        (**self).clear_poison()
+    }
+}
+
+/// Wraps a freshly acquired `guard` according to the `policy`,
+/// unless the lock is `poisoned`.
+///
+/// Used directly only where the backend stays exclusively borrowed by the `guard`,
+/// so that the poisoning has to be sampled right before the acquisition.
+#[inline]
+pub(crate) fn wrap_guard<G>(
+    flag: &AtomicBool,
+    policy: PoisonPolicy,
+    poisoned: bool,
+    guard: G,
+    armed: bool,
+) -> Result<PoisonGuard<'_, G>, TryLockError>
+{
+    if poisoned {
+        return Err(TryLockError::Poisoned);
+    }
+    let flag = match policy {
+        PoisonPolicy::Ignore => None,
+        PoisonPolicy::Propagate | PoisonPolicy::PoisonOnPanic => Some(flag),
+    };
+    Ok(PoisonGuard { flag: PoisonFlag { flag, armed }, guard })
+}
+
+/// Handles the backend's own poisoning reported by its fallible method,
+/// which doesn't hand the guard back.
+///
+/// It stays [`TryLockError::Poisoned`] if the adapter reports the poisoning too,
+/// and otherwise becomes [`TryLockError::WouldBlock`],
+/// as the guard could only be acquired again by blocking.
+#[inline]
+pub(crate) fn backend_poisoning<G>(
+    poisoned: bool,
+    result: Result<G, TryLockError>,
+) -> Result<G, TryLockError>
+{
+    match result {
+        Err(TryLockError::Poisoned) if !poisoned => Err(TryLockError::WouldBlock),
+        result => result,
+    }
+}
+
+/// Unwraps the result of [`wrap_guard`] for the infallible methods.
+#[inline]
+#[track_caller]
+pub(crate) fn expect_healthy<G>(guard: Result<G, TryLockError>) -> G {
+    guard.unwrap_or_else(|err| panic!("{err}"))
+}
+
+impl PoisonFlag<'_>
+{
+    #[inline]
+    pub(crate) fn arm(mut self) -> Self {
+        self.armed = true;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn disarm(mut self) -> Self {
+        self.armed = false;
+        self
+    }
+
+    /// Keeps the flag armed while `f` runs, so that a panic in it poisons the lock.
+    #[inline]
+    pub(crate) fn armed_while<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        let armed = std::mem::replace(&mut self.armed, true);
+        let result = f();
+        self.armed = armed;
+        result
+    }
+
+    /// Keeps the flag disarmed while `f` runs, as no lock is held meanwhile.
+    #[inline]
+    pub(crate) fn disarmed_while<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        let armed = std::mem::replace(&mut self.armed, false);
+        let result = f();
+        self.armed = armed;
+        result
+    }
+}
+
+impl Drop for PoisonFlag<'_>
+{
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(flag) = self.flag {
+            if self.armed && thread::panicking() {
+                flag.store(true, Ordering::Release)
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,145 @@
+use {
+    crate::{GuardedTarget, UpgradableReadGuard},
+    std::ops::Deref,
+};
+
+/// Provides closure-scoped constant access to the guarded data.
+///
+/// Implemented for every [`ReadApi`](crate::ReadApi) implementor,
+/// and natively for backends that can't hand out guards,
+/// such as thread-locals.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::RefCell;
+/// use parking_lot::RwLock;
+/// use read_write_api::{
+///     RwApiWrapperOwned,
+///     WithReadApi,
+///     WithUpgradableApi,
+///     WithWriteApi,
+/// };
+///
+/// thread_local! {
+///     static COUNTER: RefCell<u64> = const { RefCell::new(1) };
+/// }
+///
+/// fn increment<T>(mut x: T) -> u64
+///     where T: WithReadApi<Target=u64> + WithWriteApi + WithUpgradableApi
+/// {
+///     x.with_write(|value| *value += 1);
+///     x.with_upgradable(|value| {
+///         if **value == 2 {
+///             value.with_upgraded(|value| *value *= 10)
+///         }
+///     });
+///     x.with_read(|value| *value)
+/// }
+///
+/// assert_eq!(increment(&COUNTER), 20);
+/// assert_eq!(increment(&COUNTER), 21);
+/// assert_eq!(increment(RwLock::new(1)), 20);
+/// assert_eq!(increment(&RwLock::new(3)), 4);
+/// assert_eq!(increment(RwApiWrapperOwned(1)), 20);
+/// ```
+pub trait WithReadApi: GuardedTarget
+{
+    /// Calls `f` with a constant reference to the guarded data.
+    fn with_read<R, F>(&self, f: F) -> R
+        where F: FnOnce(&Self::Target) -> R;
+}
+
+/// Provides closure-scoped mutable access to the guarded data.
+///
+/// Implemented for every [`WriteApi`](crate::WriteApi) implementor,
+/// and natively for backends that can't hand out guards,
+/// such as thread-locals.
+///
+/// # Example
+///
+/// See the [`WithReadApi`] docs for usage examples.
+pub trait WithWriteApi: GuardedTarget
+{
+    /// Calls `f` with a mutable reference to the guarded data.
+    fn with_write<R, F>(&mut self, f: F) -> R
+        where F: FnOnce(&mut Self::Target) -> R;
+}
+
+/// Provides closure-scoped upgradable access to the guarded data.
+///
+/// Implemented for every [`UpgradableReadApi`](crate::UpgradableReadApi) implementor,
+/// and natively for backends that can't hand out guards,
+/// such as thread-locals.
+///
+/// # Example
+///
+/// See the [`WithReadApi`] docs for usage examples.
+pub trait WithUpgradableApi: GuardedTarget
+{
+    /// Calls `f` with an [`UpgradableScope`] over the guarded data.
+    fn with_upgradable<R, F>(&mut self, f: F) -> R
+        where F: FnOnce(&mut UpgradableScope<'_, Self::Target>) -> R;
+}
+
+/// Upgradable access to the guarded data given to [`WithUpgradableApi::with_upgradable`].
+///
+/// Dereferences to the guarded data and can be temporarily upgraded
+/// with [`Self::with_upgraded`].
+pub struct UpgradableScope<'a, T: ?Sized> {
+    access: &'a mut dyn UpgradableAccess<T>,
+}
+
+impl<'a, T: ?Sized> UpgradableScope<'a, T>
+{
+    /// Creates a scope over an upgradable read `guard`.
+    #[inline]
+    pub fn new<G>(guard: &'a mut G) -> Self
+        where G: UpgradableReadGuard<Target=T>
+    {
+        Self { access: guard }
+    }
+
+    /// Generalizes
+    /// [`RwLockUpgradableReadGuard::with_upgraded`](parking_lot::RwLockUpgradableReadGuard::with_upgraded).
+    #[inline]
+    pub fn with_upgraded<R, F>(&mut self, f: F) -> R
+        where F: FnOnce(&mut T) -> R
+    {
+        let mut f = Some(f);
+        let mut result = None;
+        (*self.access).upgraded(&mut |data| result = f.take().map(|f| f(data)));
+        result.expect("the upgraded closure must be called exactly once")
+    }
+}
+
+impl<T: ?Sized> Deref for UpgradableScope<'_, T>
+{
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        (*self.access).get()
+    }
+}
+
+/// Object-safe part of [`UpgradableReadGuard`] used by [`UpgradableScope`].
+trait UpgradableAccess<T: ?Sized>
+{
+    fn get(&self) -> &T;
+
+    fn upgraded(&mut self, f: &mut dyn FnMut(&mut T));
+}
+
+impl<G: UpgradableReadGuard> UpgradableAccess<G::Target> for G
+{
+    #[inline(always)]
+    fn get(&self) -> &G::Target {
+        self
+    }
+
+    #[inline]
+    fn upgraded(&mut self, f: &mut dyn FnMut(&mut G::Target)) {
+        self.with_upgraded(f)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,168 @@
+use std::{fmt, marker::PhantomData, ops::DerefMut, ptr::NonNull, rc::Rc};
+
+/// Splits a write guard into disjoint [`GuardPart`]s,
+/// each of which implements the [`RwApi`](crate::RwApi) family for its own part of the data.
+///
+/// The parent guard is released only after all of its parts are dropped.
+/// Implemented for every [`DerefMut`] guard,
+/// including [`WriteApi::WriteGuard`](crate::WriteApi::WriteGuard)
+/// and [`DowngradableWriteGuard`](crate::DowngradableWriteGuard)s.
+/// See [`split_guard!`](crate::split_guard) for splitting a guard by struct fields.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{RwApi, SplitGuard};
+///
+/// struct State {
+///     counter: u64,
+///     log: Vec<String>,
+/// }
+///
+/// fn bump(mut x: impl RwApi<Target=u64>) {
+///     *x.write() += 1
+/// }
+///
+/// fn record(mut x: impl RwApi<Target=Vec<String>>) {
+///     x.write().push("bumped".into())
+/// }
+///
+/// let lock = RwLock::new(State { counter: 0, log: vec![] });
+///
+/// let (counter, log) = SplitGuard::split2(lock.write(), |s| (&mut s.counter, &mut s.log));
+/// bump(counter);
+/// assert!(lock.try_read().is_none());
+/// record(log);
+///
+/// let state = lock.read();
+/// assert_eq!((state.counter, state.log.len()), (1, 1));
+/// ```
+pub trait SplitGuard: DerefMut + Sized
+{
+    /// Splits the guard into two disjoint parts returned by `f`.
+    fn split2<A, B, F>(s: Self, f: F) -> (GuardPart<Self, A>, GuardPart<Self, B>)
+        where
+            A: ?Sized,
+            B: ?Sized,
+            F: FnOnce(&mut Self::Target) -> (&mut A, &mut B);
+
+    /// Splits the guard into three disjoint parts returned by `f`.
+    fn split3<A, B, C, F>(s: Self, f: F) -> (GuardPart<Self, A>, GuardPart<Self, B>, GuardPart<Self, C>)
+        where
+            A: ?Sized,
+            B: ?Sized,
+            C: ?Sized,
+            F: FnOnce(&mut Self::Target) -> (&mut A, &mut B, &mut C);
+
+    /// Splits the guard into four disjoint parts returned by `f`.
+    #[allow(clippy::type_complexity)]
+    fn split4<A, B, C, D, F>(s: Self, f: F)
+        -> (GuardPart<Self, A>, GuardPart<Self, B>, GuardPart<Self, C>, GuardPart<Self, D>)
+        where
+            A: ?Sized,
+            B: ?Sized,
+            C: ?Sized,
+            D: ?Sized,
+            F: FnOnce(&mut Self::Target) -> (&mut A, &mut B, &mut C, &mut D);
+}
+
+/// Splits a write guard into [`GuardPart`]s over the listed fields of the guarded struct.
+///
+/// Expands to the [`SplitGuard`] call of the matching arity,
+/// so the borrow checker makes sure that the fields are distinct.
+/// Both named fields and tuple indices are supported.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{split_guard, ReadApi, WriteApi};
+///
+/// struct Point {
+///     x: f64,
+///     y: f64,
+///     z: f64,
+/// }
+///
+/// let lock = RwLock::new(Point { x: 1., y: 2., z: 3. });
+///
+/// let (mut x, y, mut z) = split_guard!(lock.write() => x, y, z);
+/// std::mem::swap(&mut *x.write(), &mut *z.write());
+/// assert_eq!(*y.read(), 2.);
+/// drop((x, y, z));
+///
+/// let pair = RwLock::new((1, String::new()));
+/// let (number, mut text) = split_guard!(pair.write() => 0, 1);
+/// text.write().push_str(&number.read().to_string());
+/// drop((number, text));
+///
+/// assert_eq!(lock.read().x, 3.);
+/// assert_eq!(pair.read().1, "1");
+/// ```
+#[macro_export]
+macro_rules! split_guard {
+    ($guard:expr => $a:tt, $b:tt $(,)?) => {
+        $crate::SplitGuard::split2($guard, |data| (&mut data.$a, &mut data.$b))
+    };
+    ($guard:expr => $a:tt, $b:tt, $c:tt $(,)?) => {
+        $crate::SplitGuard::split3($guard, |data| (&mut data.$a, &mut data.$b, &mut data.$c))
+    };
+    ($guard:expr => $a:tt, $b:tt, $c:tt, $d:tt $(,)?) => {
+        $crate::SplitGuard::split4(
+            $guard,
+            |data| (&mut data.$a, &mut data.$b, &mut data.$c, &mut data.$d),
+        )
+    };
+}
+
+/// Part `U` of the data of the write guard `G` returned by [`SplitGuard`].
+///
+/// Implements the [`RwApi`](crate::RwApi) family over the part
+/// and keeps the parent guard alive.
+///
+/// Like `&mut U`, it's invariant in `U`:
+///
+/// ```compile_fail
+/// use read_write_api::GuardPart;
+///
+/// fn shorten<'s, G>(part: GuardPart<G, &'static str>) -> GuardPart<G, &'s str> {
+///     part
+/// }
+/// ```
+pub struct GuardPart<G, U: ?Sized> {
+    data: NonNull<U>,
+    _guard: Rc<G>,
+    // Keeps `U` invariant like in `&mut U`.
+    _variance: PhantomData<*mut U>,
+}
+
+impl<G, U: ?Sized> GuardPart<G, U>
+{
+    /// `data` must be mutably borrowed from `guard`
+    /// and must not overlap with the data of other parts of the same guard.
+    #[inline(always)]
+    pub(crate) unsafe fn new(data: NonNull<U>, guard: Rc<G>) -> Self {
+        Self { data, _guard: guard, _variance: PhantomData }
+    }
+
+    #[inline(always)]
+    pub(crate) fn get(&self) -> &U {
+        // SAFETY: the data is mutably borrowed from `_guard`, which is alive as long as `self`.
+        unsafe { self.data.as_ref() }
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_mut(&mut self) -> &mut U {
+        // SAFETY: the data is mutably borrowed from `_guard`, which is alive as long as `self`,
+        // and doesn't overlap with the data of other parts.
+        unsafe { self.data.as_mut() }
+    }
+}
+
+impl<G, U: ?Sized + fmt::Debug> fmt::Debug for GuardPart<G, U>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.get(), f)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+/// Provides an interface to query whether a lock is held, without taking it.
+///
+/// The answers are snapshots, which may be stale by the time they are used,
+/// so they are meant for diagnostics and assertions rather than synchronization.
+/// Wrappers are never locked.
+///
+/// Not implemented for [`std::sync::RwLock`]: it can only be queried by trying to take it,
+/// which briefly holds the lock and gives false answers for writer-preferring implementations.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::RefCell;
+/// use parking_lot::RwLock;
+/// use read_write_api::{LockStateApi, RwApiWrapperOwned};
+///
+/// fn check_idle(x: &impl LockStateApi) {
+///     x.debug_assert_unlocked();
+///     assert!(!x.is_locked());
+///     assert_eq!(x.reader_count(), Some(0));
+/// }
+///
+/// let lock = RwLock::new(1);
+/// check_idle(&lock);
+/// {
+///     let _guard = lock.read();
+///     assert!(lock.is_locked());
+///     assert!(!lock.is_locked_exclusive());
+///     assert_eq!(lock.reader_count(), None);
+///     lock.debug_assert_not_locked_exclusive();
+/// }
+/// {
+///     let _guard = lock.write();
+///     assert!(lock.is_locked_exclusive());
+/// }
+/// check_idle(&lock);
+///
+/// let cell = RefCell::new(1);
+/// let _guard = cell.borrow_mut();
+/// assert!(cell.is_locked_exclusive());
+/// assert_eq!(cell.reader_count(), Some(0));
+///
+/// check_idle(&RwApiWrapperOwned(1));
+/// ```
+pub trait LockStateApi
+{
+    /// Generalizes [`RwLock::is_locked`](parking_lot::RwLock::is_locked).
+    fn is_locked(&self) -> bool;
+
+    /// Generalizes [`RwLock::is_locked_exclusive`](parking_lot::RwLock::is_locked_exclusive).
+    fn is_locked_exclusive(&self) -> bool;
+
+    /// Returns the number of readers holding the lock,
+    /// or [`None`] if the backend can't tell.
+    ///
+    /// The default implementation never tells.
+    /// The backends of this crate don't count their readers,
+    /// so they only tell while the lock isn't held shared, and wrappers always report `Some(0)`.
+    #[inline]
+    fn reader_count(&self) -> Option<usize> {
+        None
+    }
+
+    /// Panics in debug builds if the lock is held in any mode.
+    #[inline]
+    #[track_caller]
+    fn debug_assert_unlocked(&self) {
+        debug_assert!(!self.is_locked(), "the lock is held")
+    }
+
+    /// Panics in debug builds if the lock is held exclusively.
+    #[inline]
+    #[track_caller]
+    fn debug_assert_not_locked_exclusive(&self) {
+        debug_assert!(!self.is_locked_exclusive(), "the lock is held exclusively")
+    }
+}
+
+/// Provides an interface to tell locks apart.
+///
+/// Views of the same lock, like references to it or [`Lensed`](crate::Lensed) projections,
+/// share its address, which gives a consistent order to acquire several locks in.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{Lensed, LockIdentityApi};
+///
+/// let (a, b) = (RwLock::new((1, 2)), RwLock::new((1, 2)));
+/// let field = Lensed::from_fns(&a, |p| &p.1, |p| &mut p.1);
+/// assert_eq!(field.lock_addr(), a.lock_addr());
+/// assert_ne!(a.lock_addr(), b.lock_addr());
+/// ```
+pub trait LockIdentityApi
+{
+    /// Returns the address identifying the underlying lock.
+    fn lock_addr(&self) -> *const ();
+}
@@ -36,7 +36,116 @@ pub struct RwApiWrapper<'a, T: ?Sized>(
 /// # Example
 ///
 /// See the [`RwApi`](crate::RwApi) docs for usage examples.
-pub struct RwApiWrapperOwned<T>(
+pub struct RwApiWrapperOwned<T: ?Sized>(
     /// Wrapped owned object.
     pub T
+);
+
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// [`ReadApi`](crate::ReadApi) owning wrapper for solitary objects.
+///
+/// # Example
+///
+/// ```rust
+/// use read_write_api::{ReadApi, ReadApiWrapperOwned};
+///
+/// fn config() -> impl ReadApi<Target=String> {
+///     ReadApiWrapperOwned("verbose".to_string())
+/// }
+///
+/// assert_eq!(*config().read(), "verbose");
+/// ```
+pub struct ReadApiWrapperOwned<T: ?Sized>(
+    /// Wrapped owned object.
+    pub T
+);
+
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// Read-only adapter over any [`ReadApi`](crate::ReadApi) implementor.
+///
+/// Implements the read traits implemented by the wrapped object
+/// and deliberately none of the write ones.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{ReadApi, ReadOnly, RwApiWrapperOwned, TryReadApi};
+///
+/// fn total(x: impl ReadApi<Target=Vec<u64>>) -> u64 {
+///     x.read().iter().sum()
+/// }
+///
+/// let lock = RwLock::new(vec![1, 2]);
+/// assert_eq!(total(ReadOnly::new(&lock)), 3);
+/// assert_eq!(total(&ReadOnly::new(RwApiWrapperOwned(vec![3]))), 3);
+///
+/// let read_only = ReadOnly::new(&lock);
+/// let _writer = lock.write();
+/// assert!(read_only.try_read().is_err());
+/// ```
+pub struct ReadOnly<L: ?Sized>(pub(crate) L);
+
+impl<L> ReadOnly<L>
+{
+    /// Wraps `inner` into the read-only adapter.
+    #[inline(always)]
+    pub fn new(inner: L) -> Self {
+        Self(inner)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// [`ReadApi`](crate::ReadApi) wrapper for constant references,
+/// which [`Target`](crate::GuardedTarget::Target) is `T` rather than `&T`.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{FlatReadApiWrapper, ReadApi, ReadApiWrapperOwned};
+///
+/// fn double(x: impl ReadApi<Target=u64>) -> u64 {
+///     *x.read() * 2
+/// }
+///
+/// let value = 2;
+/// assert_eq!(double(FlatReadApiWrapper(&value)), 4);
+/// assert_eq!(double(&FlatReadApiWrapper(&value)), 4);
+/// assert_eq!(double(ReadApiWrapperOwned(3)), 6);
+/// assert_eq!(double(&RwLock::new(4)), 8);
+/// ```
+pub struct FlatReadApiWrapper<'a, T: ?Sized>(
+    /// Wrapped reference.
+    pub &'a T
+);
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// [`RwApi`](crate::RwApi) wrapper for mutable references,
+/// which [`Target`](crate::GuardedTarget::Target) is `T` rather than `&mut T`.
+///
+/// # Example
+///
+/// ```rust
+/// use parking_lot::RwLock;
+/// use read_write_api::{FlatRwApiWrapper, RwApi, RwApiWrapperOwned};
+///
+/// fn do_something(mut x: impl RwApi<Target=u64>) -> u64 {
+///     if *x.read() == 1 {
+///         *x.write() = 2
+///     }
+///     *x.read()
+/// }
+///
+/// let mut value = 1;
+/// assert_eq!(do_something(FlatRwApiWrapper(&mut value)), 2);
+/// assert_eq!(value, 2);
+/// assert_eq!(do_something(&mut FlatRwApiWrapper(&mut 3)), 3);
+///
+/// assert_eq!(do_something(RwApiWrapperOwned(1)), 2);
+/// assert_eq!(do_something(&RwLock::new(1)), 2);
+/// ```
+pub struct FlatRwApiWrapper<'a, T: ?Sized>(
+    /// Wrapped reference.
+    pub &'a mut T
 );
\ No newline at end of file